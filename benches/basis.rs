@@ -0,0 +1,55 @@
+//! Compares the array and bitmask encodings of basis blades by computing
+//! the geometric product of all pairs of basis blades.
+//!
+//! Run with `cargo bench --bench basis`.
+
+#![feature(test)]
+
+extern crate test;
+
+use std::hint::black_box;
+
+use generic_ga::{Algebra, BitMetric, NonzeroBasis, NonzeroBitBasis};
+use test::Bencher;
+
+macro_rules! bench_dimension {
+    ($array:ident, $bitmask:ident, $p:literal, $q:literal, $r:literal) => {
+        #[bench]
+        fn $array(b: &mut Bencher) {
+            let metric = Algebra::<$p, $q, $r>::metric();
+            let blades: Vec<_> = (0..1 << ($p + $q + $r))
+                .map(NonzeroBasis::<{ $p + $q + $r }>::from_index)
+                .collect();
+            b.iter(|| {
+                for &lhs in &blades {
+                    for &rhs in &blades {
+                        black_box(black_box(lhs).geometric(rhs, metric));
+                    }
+                }
+            });
+        }
+
+        #[bench]
+        fn $bitmask(b: &mut Bencher) {
+            let metric = BitMetric::new(Algebra::<$p, $q, $r>::metric());
+            let blades: Vec<_> = (0..1 << ($p + $q + $r))
+                .map(|index| {
+                    NonzeroBitBasis::from_array(NonzeroBasis::<{ $p + $q + $r }>::from_index(index))
+                })
+                .collect();
+            b.iter(|| {
+                for &lhs in &blades {
+                    for &rhs in &blades {
+                        black_box(black_box(lhs).geometric(rhs, metric));
+                    }
+                }
+            });
+        }
+    };
+}
+
+bench_dimension!(array_dim_02, bitmask_dim_02, 2, 0, 0);
+bench_dimension!(array_dim_04, bitmask_dim_04, 3, 0, 1);
+bench_dimension!(array_dim_06, bitmask_dim_06, 5, 1, 0);
+bench_dimension!(array_dim_08, bitmask_dim_08, 6, 1, 1);
+bench_dimension!(array_dim_10, bitmask_dim_10, 8, 1, 1);
@@ -0,0 +1,445 @@
+//! Generates the types of a specific geometric algebra as plain structs with unrolled products,
+//! which only need stable Rust and no `generic_const_exprs`:
+//! ```
+//! generic_ga_macros::algebra!(PGA3 = (3, 0, 1));
+//!
+//! let (e0, e1) = (PGA3::Vector([1.0, 0.0, 0.0, 0.0]), PGA3::Vector([0.0, 1.0, 0.0, 0.0]));
+//! let e01: PGA3::Bivector = e0 ^ e1;
+//! assert_eq!(e01.to_string(), "1e01");
+//! assert_eq!((e0 * e1).to_string(), "1e01");
+//! ```
+//! The signs of the products are computed by the bitmask rules of [generic_ga::NonzeroBitBasis],
+//! such that the generated types agree with [generic_ga::MV].
+
+use std::fmt::Write;
+
+use generic_ga::{BitMetric, Maybe::Just, NonzeroBitBasis, Product, Sign};
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// The generated code grows with `4^DIM`, which is too much beyond this.
+const MAX_DIM: usize = 8;
+
+const GRADE_NAMES: [&str; MAX_DIM + 1] = [
+    "Scalar",
+    "Vector",
+    "Bivector",
+    "Trivector",
+    "Quadvector",
+    "Pentavector",
+    "Hexavector",
+    "Heptavector",
+    "Octovector",
+];
+
+/// `algebra!(NAME = (P, Q, R))` generates the module `NAME` of the algebra with `P` positive,
+/// `Q` negative and `R` degenerate dimensions, in this order like [generic_ga::Algebra].
+///
+/// It contains a struct of `f64` coefficients for each grade (`Scalar`, `Vector`, `Bivector`,
+/// ..., with `Pseudoscalar` as alias) and for the `Even` and `Odd` parts.
+/// The coefficients are ordered by the bitmask index of their basis blades, see `INDICES`.
+///
+/// Operators:
+/// - `*`: Geometric product of all types, which is `Even` or `Odd` by the parity of the operands
+/// - `^`, `&`, `|`, `>>`, `<<`: Exterior, regressive, inner product and contractions of grades
+/// - `+`, `-`, `* f64` of the same type
+///
+/// Besides `reverse`, `involute` and `conjugate`, grades have the Poincaré `dual` (`!`),
+/// and all grades convert into `Even` or `Odd`.
+#[proc_macro]
+pub fn algebra(input: TokenStream) -> TokenStream {
+    let code = match parse(input) {
+        Ok((name, p, q, r)) => generate(&name, p, q, r),
+        Err(message) => format!("compile_error!({message:?});"),
+    };
+    code.parse().unwrap()
+}
+
+/// Parses `NAME = (P, Q, R)`.
+fn parse(input: TokenStream) -> Result<(String, usize, usize, usize), String> {
+    const USAGE: &str = "expected `NAME = (P, Q, R)`";
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let [
+        TokenTree::Ident(name),
+        TokenTree::Punct(equals),
+        TokenTree::Group(signature),
+    ] = &tokens[..]
+    else {
+        return Err(USAGE.into());
+    };
+    if equals.as_char() != '=' || signature.delimiter() != Delimiter::Parenthesis {
+        return Err(USAGE.into());
+    }
+
+    let mut dims = Vec::new();
+    for token in signature.stream() {
+        match token {
+            TokenTree::Literal(literal) => dims.push(
+                literal
+                    .to_string()
+                    .parse::<usize>()
+                    .map_err(|_| format!("`{literal}` is not a dimension"))?,
+            ),
+            TokenTree::Punct(comma) if comma.as_char() == ',' => {}
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let [p, q, r] = dims[..] else {
+        return Err(USAGE.into());
+    };
+    if p + q + r > MAX_DIM {
+        return Err(format!("at most {MAX_DIM} dimensions are supported"));
+    }
+    Ok((name.to_string(), p, q, r))
+}
+
+/// A generated struct, storing the coefficients of the basis blades `units`.
+struct Part {
+    name: String,
+    units: Vec<u64>,
+    /// The parity of the grades, which determines the type of geometric products.
+    odd: bool,
+    /// The grade of the k-vector types.
+    grade: Option<usize>,
+}
+
+impl Part {
+    fn position(&self, unit: u64) -> Option<usize> {
+        self.units.iter().position(|&u| u == unit)
+    }
+}
+
+fn generate(name: &str, p: usize, q: usize, r: usize) -> String {
+    let metric = BitMetric::signature(p, q, r);
+    let dim = p + q + r;
+    let units = |keep: &dyn Fn(usize) -> bool| -> Vec<u64> {
+        (0..1u64 << dim)
+            .filter(|unit| keep(unit.count_ones() as usize))
+            .collect()
+    };
+
+    let mut parts: Vec<Part> = (0..=dim)
+        .map(|k| Part {
+            name: GRADE_NAMES[k].into(),
+            units: units(&|grade| grade == k),
+            odd: k % 2 == 1,
+            grade: Some(k),
+        })
+        .collect();
+    for (name, odd) in [("Even", false), ("Odd", true)] {
+        parts.push(Part {
+            name: name.into(),
+            units: units(&|grade| (grade % 2 == 1) == odd),
+            odd,
+            grade: None,
+        });
+    }
+    let (grades, even, odd) = (&parts[..=dim], &parts[dim + 1], &parts[dim + 2]);
+
+    let mut code = String::new();
+    let w = &mut code;
+    writeln!(
+        w,
+        "/// The geometric algebra with the signature `({p}, {q}, {r})`."
+    )
+    .unwrap();
+    writeln!(
+        w,
+        "#[allow(non_snake_case, clippy::suspicious_arithmetic_impl)] pub mod {name} {{"
+    )
+    .unwrap();
+    writeln!(w, "pub type Pseudoscalar = {};", GRADE_NAMES[dim]).unwrap();
+    write_format(w, dim);
+
+    for part in &parts {
+        write_part(w, part);
+    }
+    for part in grades {
+        let k = part.grade.unwrap();
+        let dual = &grades[dim - k];
+        let map = |basis: NonzeroBitBasis| Just(basis.dual(metric));
+        write_unary(
+            w,
+            part,
+            "dual",
+            "The Poincaré dual, see `MV::dual`.",
+            dual,
+            map,
+        );
+        writeln!(
+            w,
+            "impl ::core::ops::Not for {} {{ type Output = {}; \
+             fn not(self) -> {} {{ self.dual() }} }}",
+            part.name, dual.name, dual.name
+        )
+        .unwrap();
+        let parity = if part.odd { odd } else { even };
+        write_into(w, part, parity);
+    }
+    for lhs in &parts {
+        for rhs in &parts {
+            let output = if lhs.odd == rhs.odd { even } else { odd };
+            let (trait_name, method) = ("Mul", "mul");
+            write_product(
+                w,
+                lhs,
+                rhs,
+                output,
+                trait_name,
+                method,
+                Product::Geometric,
+                metric,
+            );
+        }
+    }
+    let products = [
+        ("BitXor", "bitxor", Product::Exterior),
+        ("BitAnd", "bitand", Product::Regressive),
+        ("BitOr", "bitor", Product::Inner),
+        ("Shr", "shr", Product::LeftContraction),
+        ("Shl", "shl", Product::RightContraction),
+    ];
+    for lhs in grades {
+        for rhs in grades {
+            let (i, j) = (lhs.grade.unwrap(), rhs.grade.unwrap());
+            for (trait_name, method, product) in products {
+                let k = match product {
+                    Product::Exterior if i + j <= dim => i + j,
+                    Product::Regressive if i + j >= dim => i + j - dim,
+                    Product::Inner => i.abs_diff(j),
+                    Product::LeftContraction if i <= j => j - i,
+                    Product::RightContraction if i >= j => i - j,
+                    _ => continue,
+                };
+                let output = &grades[k];
+                write_product(w, lhs, rhs, output, trait_name, method, product, metric);
+            }
+        }
+    }
+    writeln!(w, "}}").unwrap();
+    code
+}
+
+/// The struct with its constants, unary operations, linear operators and formatting.
+fn write_part(w: &mut String, part: &Part) {
+    let (name, n) = (&part.name, part.units.len());
+    let description = match part.grade {
+        Some(k) => format!("The grade {k} part"),
+        None => format!("The {} part", name.to_lowercase()),
+    };
+    writeln!(
+        w,
+        "/// {description}, storing the coefficients of the basis blades `INDICES`."
+    )
+    .unwrap();
+    writeln!(
+        w,
+        "#[derive(Clone, Copy, Debug, PartialEq)] pub struct {name}(pub [f64; {n}]);"
+    )
+    .unwrap();
+    writeln!(w, "impl {name} {{").unwrap();
+    writeln!(w, "pub const ZERO: Self = {name}([0.0; {n}]);").unwrap();
+    writeln!(w, "/// The bitmask of the basis blade of each coefficient.").unwrap();
+    writeln!(w, "pub const INDICES: [u64; {n}] = {:?};", part.units).unwrap();
+    writeln!(w, "}}").unwrap();
+
+    write_unary(w, part, "reverse", "The reverse.", part, |basis| {
+        Just(basis.reverse())
+    });
+    write_unary(
+        w,
+        part,
+        "involute",
+        "The grade involution.",
+        part,
+        |basis| Just(basis.involute()),
+    );
+    write_unary(
+        w,
+        part,
+        "conjugate",
+        "The Clifford conjugate.",
+        part,
+        |basis| Just(basis.conjugate()),
+    );
+
+    for (trait_name, method, operator) in [("Add", "add", "+"), ("Sub", "sub", "-")] {
+        let terms = (0..n)
+            .map(|i| format!("a[{i}] {operator} b[{i}]"))
+            .collect::<Vec<_>>();
+        writeln!(
+            w,
+            "impl ::core::ops::{trait_name} for {name} {{ type Output = Self; \
+             fn {method}(self, rhs: Self) -> Self {{ let (a, b) = (self.0, rhs.0); \
+             {name}([{}]) }} }}",
+            terms.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(
+        w,
+        "impl ::core::ops::Neg for {name} {{ type Output = Self; \
+         fn neg(self) -> Self {{ {name}(self.0.map(|value| -value)) }} }}"
+    )
+    .unwrap();
+    writeln!(
+        w,
+        "impl ::core::ops::Mul<f64> for {name} {{ type Output = Self; \
+         fn mul(self, rhs: f64) -> Self {{ {name}(self.0.map(|value| value * rhs)) }} }}"
+    )
+    .unwrap();
+    writeln!(
+        w,
+        "impl ::core::fmt::Display for {name} {{ \
+         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{ \
+         format(f, &self.0, &Self::INDICES) }} }}"
+    )
+    .unwrap();
+}
+
+/// Formats like `MV`, ordered by grade.
+fn write_format(w: &mut String, dim: usize) {
+    writeln!(
+        w,
+        "fn format(f: &mut ::core::fmt::Formatter, values: &[f64], indices: &[u64]) \
+         -> ::core::fmt::Result {{
+            let mut factors: ::std::vec::Vec<(f64, ::std::string::String)> = values
+                .iter()
+                .zip(indices)
+                .filter(|(value, _)| **value != 0.0)
+                .map(|(&value, &index)| {{
+                    let digits: ::std::string::String = (0..{dim})
+                        .filter(|k| index & (1 << k) != 0)
+                        .map(|k: u64| k.to_string())
+                        .collect();
+                    (value, if index == 0 {{ digits }} else {{ ::std::format!(\"e{{digits}}\") }})
+                }})
+                .collect();
+            if factors.is_empty() {{
+                return f.write_str(\"0\");
+            }}
+            factors.sort_by_key(|(_, suffix)| suffix.len());
+            for (i, (value, suffix)) in factors.into_iter().enumerate() {{
+                if i > 0 {{
+                    f.write_str(\" + \")?;
+                }}
+                ::core::write!(f, \"{{value}}{{suffix}}\")?;
+            }}
+            Ok(())
+        }}"
+    )
+    .unwrap();
+}
+
+/// A method mapping each basis blade of `part` to a signed basis blade of `output`.
+fn write_unary(
+    w: &mut String,
+    part: &Part,
+    method: &str,
+    doc: &str,
+    output: &Part,
+    map: impl Fn(NonzeroBitBasis) -> generic_ga::BitBasis,
+) {
+    let mut terms = vec![String::from("0.0"); output.units.len()];
+    for (i, &unit) in part.units.iter().enumerate() {
+        if let Just(basis) = map(NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit,
+        }) {
+            let sign = if basis.sign == Sign::Neg { "-" } else { "" };
+            terms[output.position(basis.unit).unwrap()] = format!("{sign}a[{i}]");
+        }
+    }
+    writeln!(
+        w,
+        "impl {} {{ /// {doc}\n#[allow(unused_variables)] pub fn {method}(self) -> {} {{ \
+         let a = self.0; {}([{}]) }} }}",
+        part.name,
+        output.name,
+        output.name,
+        terms.join(", ")
+    )
+    .unwrap();
+}
+
+/// Embeds a grade into its `Even` or `Odd` part.
+fn write_into(w: &mut String, part: &Part, parity: &Part) {
+    let mut terms = vec![String::from("0.0"); parity.units.len()];
+    for (i, &unit) in part.units.iter().enumerate() {
+        terms[parity.position(unit).unwrap()] = format!("a[{i}]");
+    }
+    writeln!(
+        w,
+        "impl ::core::convert::From<{}> for {} {{ fn from(value: {}) -> Self {{ \
+         let a = value.0; {}([{}]) }} }}",
+        part.name,
+        parity.name,
+        part.name,
+        parity.name,
+        terms.join(", ")
+    )
+    .unwrap();
+}
+
+/// The operator `trait_name` computing `product` with all pairs of coefficients unrolled.
+#[allow(clippy::too_many_arguments)]
+fn write_product(
+    w: &mut String,
+    lhs: &Part,
+    rhs: &Part,
+    output: &Part,
+    trait_name: &str,
+    method: &str,
+    product: Product,
+    metric: BitMetric,
+) {
+    let mut terms = vec![Vec::new(); output.units.len()];
+    for (i, &a) in lhs.units.iter().enumerate() {
+        for (j, &b) in rhs.units.iter().enumerate() {
+            let a = NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit: a,
+            };
+            let b = NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit: b,
+            };
+            if let Just(basis) = a.product(b, product, metric) {
+                let position = output.position(basis.unit).unwrap();
+                terms[position].push((basis.sign, format!("a[{i}] * b[{j}]")));
+            }
+        }
+    }
+    let expressions: Vec<String> = terms
+        .into_iter()
+        .map(|terms| {
+            let mut expression = String::new();
+            for (sign, term) in terms {
+                match (sign, expression.is_empty()) {
+                    (Sign::Pos, true) => expression.push_str(&term),
+                    (Sign::Neg, true) => write!(expression, "-{term}").unwrap(),
+                    (Sign::Pos, false) => write!(expression, " + {term}").unwrap(),
+                    (Sign::Neg, false) => write!(expression, " - {term}").unwrap(),
+                }
+            }
+            if expression.is_empty() {
+                "0.0".into()
+            } else {
+                expression
+            }
+        })
+        .collect();
+    writeln!(
+        w,
+        "impl ::core::ops::{trait_name}<{}> for {} {{ type Output = {}; \
+         #[allow(unused_variables)] fn {method}(self, rhs: {}) -> {} {{ \
+         let (a, b) = (self.0, rhs.0); {}([{}]) }} }}",
+        rhs.name,
+        lhs.name,
+        output.name,
+        rhs.name,
+        output.name,
+        output.name,
+        expressions.join(", ")
+    )
+    .unwrap();
+}
@@ -0,0 +1,91 @@
+//! Compares the generated types with the generic [MV].
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use generic_ga::{CGA3, MV, PGA3, Signature, VGA3};
+use generic_ga_macros::algebra;
+
+algebra!(Pga3 = (3, 0, 1));
+algebra!(Cga3 = (4, 1, 0));
+algebra!(Vga3 = (3, 0, 0));
+
+/// Deterministic pseudo-random coefficients.
+fn values<const N: usize>(seed: usize) -> [f64; N] {
+    std::array::from_fn(|i| ((seed * 7 + i * 13 + seed * i * 5) % 9) as f64 - 4.0)
+}
+
+fn dense<A: Signature>(values: &[f64], indices: &[u64]) -> MV<A>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    let mut mv = MV::ZERO;
+    for (&value, &index) in values.iter().zip(indices) {
+        mv.0[index as usize] = value;
+    }
+    mv
+}
+
+#[test]
+fn pga3_products() {
+    use Pga3::{Bivector, Even, Odd, Trivector, Vector};
+    let mv = |values: &[f64], indices: &[u64]| dense::<PGA3>(values, indices);
+
+    for seed in 0..4 {
+        let (a, b) = (Vector(values(seed)), Bivector(values(seed + 1)));
+        let (m, n) = (Even(values(seed + 2)), Even(values(seed + 3)));
+        let (va, vb) = (mv(&a.0, &Vector::INDICES), mv(&b.0, &Bivector::INDICES));
+        let (vm, vn) = (mv(&m.0, &Even::INDICES), mv(&n.0, &Even::INDICES));
+
+        let odd: Odd = a * b;
+        assert_eq!(mv(&odd.0, &Odd::INDICES), va * vb);
+        let motor: Even = m * n;
+        assert_eq!(mv(&motor.0, &Even::INDICES), vm * vn);
+        let moved: Odd = m * a * m.reverse();
+        assert_eq!(mv(&moved.0, &Odd::INDICES), vm * va * vm.reverse());
+
+        let plane: Trivector = a ^ b;
+        assert_eq!(mv(&plane.0, &Trivector::INDICES), va ^ vb);
+        let line: Bivector = !a & !Vector(values(seed + 5));
+        let vc = mv(&Vector(values(seed + 5)).0, &Vector::INDICES);
+        assert_eq!(mv(&line.0, &Bivector::INDICES), !va & !vc);
+        assert_eq!(mv(&(a >> b).0, &Vector::INDICES), va >> vb);
+        assert_eq!(mv(&(b << a).0, &Vector::INDICES), vb << va);
+        assert_eq!(mv(&(a | b).0, &Vector::INDICES), va | vb);
+        assert_eq!(mv(&Even::from(b).0, &Even::INDICES), vb);
+        assert_eq!(mv(&(m + n * 2.0 - m).0, &Even::INDICES), vn * 2.0);
+    }
+}
+
+#[test]
+fn cga3_products() {
+    use Cga3::{Even, Odd, Pentavector, Quadvector, Vector};
+    let mv = |values: &[f64], indices: &[u64]| dense::<CGA3>(values, indices);
+
+    for seed in 0..4 {
+        let (a, b) = (Vector(values(seed)), Quadvector(values(seed + 1)));
+        let (va, vb) = (mv(&a.0, &Vector::INDICES), mv(&b.0, &Quadvector::INDICES));
+        let pseudoscalar: Pentavector = a ^ b;
+        assert_eq!(mv(&pseudoscalar.0, &Pentavector::INDICES), va ^ vb);
+        let odd: Odd = a * b;
+        assert_eq!(mv(&odd.0, &Odd::INDICES), va * vb);
+        let (m, n) = (Even(values(seed + 2)), Odd(values(seed + 3)));
+        let (vm, vn) = (mv(&m.0, &Even::INDICES), mv(&n.0, &Odd::INDICES));
+        assert_eq!(mv(&(m * n).0, &Odd::INDICES), vm * vn);
+        assert_eq!(mv(&n.conjugate().0, &Odd::INDICES), vn.conjugate());
+        assert_eq!(mv(&b.dual().0, &Vector::INDICES), vb.dual());
+    }
+}
+
+#[test]
+fn vga3_display() {
+    use Vga3::{Bivector, Scalar, Vector};
+    let (e0, e1) = (Vector([1.0, 0.0, 0.0]), Vector([0.0, 1.0, 0.0]));
+    let e01: Bivector = e0 ^ e1;
+    assert_eq!(e01.to_string(), "1e01");
+    assert_eq!((e0 * (e0 + e1 * 2.0)).to_string(), "1 + 2e01");
+    assert_eq!(Scalar::ZERO.to_string(), "0");
+    let mv = dense::<VGA3>(&(e0 * e1).0, &Vga3::Even::INDICES);
+    assert_eq!((e0 * e1).to_string(), mv.to_string());
+    let _: Vga3::Pseudoscalar = e01 ^ Vector([0.0, 0.0, 1.0]);
+}
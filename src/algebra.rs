@@ -1,8 +1,6 @@
-use crate::{
-    common::pow,
-    macros::repeat,
-    metric::{Metric, Square},
-};
+use crate::{bitmask::BitMetric, common::pow};
+#[cfg(feature = "nightly")]
+use crate::{basis::Product, cayley::CayleyTable, metric::Metric};
 
 /// A geometric algebra:
 /// - `P`: Positive dimensions
@@ -21,23 +19,124 @@ pub type VGA3 = VGA<3>;
 pub type PGA<const D: usize> = Algebra<D, 0, 1>;
 pub type PGA2 = PGA<2>;
 pub type PGA3 = PGA<3>;
+#[cfg(feature = "nightly")]
 pub type CGA<const D: usize> = Algebra<{ D + 1 }, 1, 0>;
-pub type CGA2 = CGA<2>;
-pub type CGA3 = CGA<3>;
+// Spelled out because normalizing `{ D + 1 }` through [Signature] currently ICEs the compiler.
+pub type CGA2 = Algebra<3, 1, 0>;
+pub type CGA3 = Algebra<4, 1, 0>;
 
 impl<const P: usize, const Q: usize, const R: usize> Algebra<P, Q, R> {
     pub const DIM: usize = P + Q + R;
     pub const BASIS_BLADE_COUNT: usize = pow(2, Self::DIM);
 
+    #[cfg(feature = "nightly")]
     pub const fn metric() -> Metric<{ Self::DIM }> {
-        let mut squares = [Square::Pos; Self::DIM];
-        repeat!(i in P..{P + Q} {
-            squares[i] = Square::Neg;
-        });
-        repeat!(i in {P + Q}..{Self::DIM} {
-            squares[i] = Square::Zero;
-        });
-        Metric(squares)
+        Metric::signature(P, Q)
+    }
+}
+
+/// Cayley tables of all products, such that multivector products reduce to table lookups.
+#[cfg(feature = "nightly")]
+impl<const P: usize, const Q: usize, const R: usize> Algebra<P, Q, R>
+where
+    [(); <Self as Signature>::DIM]:,
+    [(); <Self as Signature>::BASIS_BLADE_COUNT]:,
+{
+    const SIGNATURE_METRIC: Metric<{ <Self as Signature>::DIM }> = Metric::signature(P, Q);
+
+    pub const GEOMETRIC: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Geometric, Self::SIGNATURE_METRIC);
+    pub const EXTERIOR: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Exterior, Self::SIGNATURE_METRIC);
+    pub const REGRESSIVE: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Regressive, Self::SIGNATURE_METRIC);
+    pub const LEFT_CONTRACTION: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::LeftContraction, Self::SIGNATURE_METRIC);
+    pub const RIGHT_CONTRACTION: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::RightContraction, Self::SIGNATURE_METRIC);
+    pub const INNER: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Inner, Self::SIGNATURE_METRIC);
+    pub const HESTENES_INNER: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::HestenesInner, Self::SIGNATURE_METRIC);
+    pub const LEFT_ANTI_CONTRACTION: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::LeftAntiContraction, Self::SIGNATURE_METRIC);
+    pub const RIGHT_ANTI_CONTRACTION: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::RightAntiContraction, Self::SIGNATURE_METRIC);
+    pub const SCALAR: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Scalar, Self::SIGNATURE_METRIC);
+    pub const COMMUTATOR: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::Commutator, Self::SIGNATURE_METRIC);
+    pub const ANTI_COMMUTATOR: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::AntiCommutator, Self::SIGNATURE_METRIC);
+    pub const ANTI_GEOMETRIC: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::AntiGeometric, Self::SIGNATURE_METRIC);
+    pub const ANTI_SCALAR: CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }> =
+        CayleyTable::new(Product::AntiScalar, Self::SIGNATURE_METRIC);
+}
+
+/// Exposes the properties of an [Algebra] to code which is generic over the algebra itself,
+/// e.g. `MV<A: Signature>`.
+/// The inherent constants of [Algebra] cannot be used for this, as they are not
+/// unified with each other by `generic_const_exprs`.
+pub trait Signature: 'static {
+    const P: usize;
+    const Q: usize;
+    const R: usize;
+    const DIM: usize;
+    const BASIS_BLADE_COUNT: usize;
+    const BIT_METRIC: BitMetric;
+
+    #[cfg(feature = "nightly")]
+    fn metric() -> Metric<{ Self::DIM }>
+    where
+        [(); Self::DIM]:;
+
+    /// The Cayley table of `product`, evaluated at compile time.
+    #[cfg(feature = "nightly")]
+    fn cayley(product: Product) -> &'static CayleyTable<{ Self::BASIS_BLADE_COUNT }>
+    where
+        [(); Self::DIM]:,
+        [(); Self::BASIS_BLADE_COUNT]:;
+}
+
+impl<const P: usize, const Q: usize, const R: usize> Signature for Algebra<P, Q, R> {
+    const P: usize = P;
+    const Q: usize = Q;
+    const R: usize = R;
+    const DIM: usize = Algebra::<P, Q, R>::DIM;
+    const BASIS_BLADE_COUNT: usize = Algebra::<P, Q, R>::BASIS_BLADE_COUNT;
+    const BIT_METRIC: BitMetric = BitMetric::signature(P, Q, R);
+
+    #[cfg(feature = "nightly")]
+    fn metric() -> Metric<{ <Self as Signature>::DIM }>
+    where
+        [(); <Self as Signature>::DIM]:,
+    {
+        Metric::signature(P, Q)
+    }
+
+    #[cfg(feature = "nightly")]
+    fn cayley(product: Product) -> &'static CayleyTable<{ <Self as Signature>::BASIS_BLADE_COUNT }>
+    where
+        [(); <Self as Signature>::DIM]:,
+        [(); <Self as Signature>::BASIS_BLADE_COUNT]:,
+    {
+        match product {
+            Product::Geometric => &Self::GEOMETRIC,
+            Product::Exterior => &Self::EXTERIOR,
+            Product::Regressive => &Self::REGRESSIVE,
+            Product::LeftContraction => &Self::LEFT_CONTRACTION,
+            Product::RightContraction => &Self::RIGHT_CONTRACTION,
+            Product::Inner => &Self::INNER,
+            Product::HestenesInner => &Self::HESTENES_INNER,
+            Product::LeftAntiContraction => &Self::LEFT_ANTI_CONTRACTION,
+            Product::RightAntiContraction => &Self::RIGHT_ANTI_CONTRACTION,
+            Product::Scalar => &Self::SCALAR,
+            Product::Commutator => &Self::COMMUTATOR,
+            Product::AntiCommutator => &Self::ANTI_COMMUTATOR,
+            Product::AntiGeometric => &Self::ANTI_GEOMETRIC,
+            Product::AntiScalar => &Self::ANTI_SCALAR,
+        }
     }
 }
 
@@ -1,5 +1,3 @@
-use std::marker::ConstParamTy;
-
 use crate::{
     common::{even, odd},
     macros::{repeat, yeet},
@@ -8,10 +6,46 @@ use crate::{
     sign::Sign,
 };
 
+/// Names the bilinear products between blades, see [NonzeroBasis::product].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
+pub enum Product {
+    Geometric,
+    Exterior,
+    Regressive,
+    LeftContraction,
+    RightContraction,
+    /// Dorst's scalar-preserving inner product, see [NonzeroBasis::inner].
+    Inner,
+    HestenesInner,
+    LeftAntiContraction,
+    RightAntiContraction,
+    Scalar,
+    Commutator,
+    AntiCommutator,
+    AntiGeometric,
+    AntiScalar,
+}
+
+/// Names the unary operators on blades, see [NonzeroBasis::unary].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
+pub enum Unary {
+    Reverse,
+    Involute,
+    Conjugate,
+    RightComplement,
+    LeftComplement,
+    Hodge,
+    Dual,
+    AntiReverse,
+}
+
 /// Encodes the basis of a blade such that `A = B eᵢ` ⇔ `A[i]`
 /// The encoding is chosen in a way that is order-independent.
 /// Therefore the sign of the basis is stored separately.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ConstParamTy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
 pub struct NonzeroBasis<const N: usize> {
     pub sign: Sign,
     pub unit: [bool; N],
@@ -52,6 +86,13 @@ impl<const N: usize> NonzeroBasis<N> {
         self.reverse().involute()
     }
 
+    /// Anti-reverse `A̰ = (A̅~)̲`, the reverse of the complement, which reverses
+    /// the geometric antiproduct: `(A ⟇ B)̰ = B̰ ⟇ A̰`.
+    /// Its parity depends on the anti-grade like the one of the reverse depends on the grade.
+    pub const fn anti_reverse(self) -> Self {
+        self.right_complement().reverse().left_complement()
+    }
+
     pub const fn neg(self) -> Self {
         NonzeroBasis {
             sign: self.sign.neg(),
@@ -59,15 +100,104 @@ impl<const N: usize> NonzeroBasis<N> {
         }
     }
 
-    /// Poincaré duality operator
-    pub const fn dual(self) -> Self {
-        let mut dual = [false; N];
+    /// The unit of the factors missing from this blade, i.e. the unit of its complements.
+    const fn complement_unit(self) -> [bool; N] {
+        let mut complement = [false; N];
+        repeat!(i in 0..N {
+            complement[i] = !self.unit[i];
+        });
+        complement
+    }
+
+    /// The sign of the exterior product `lhs ∧ rhs` of two positive blades without common factors.
+    const fn exterior_sign(lhs: [bool; N], rhs: [bool; N]) -> Sign {
+        let mut sign = Sign::Pos;
         repeat!(i in 0..N {
-            dual[i] = !self.unit[i];
+            if lhs[i] {
+                repeat!(j in 0..i {
+                    if rhs[j] {
+                        sign = sign.neg();
+                    }
+                });
+            }
         });
+        sign
+    }
+
+    /// Right complement `A̅`, defined by `A ∧ A̅ = I`.
+    /// This does not depend on the metric.
+    pub const fn right_complement(self) -> Self {
+        let unit = self.complement_unit();
         NonzeroBasis {
-            sign: self.sign,
-            unit: dual,
+            sign: self.sign.mul(Self::exterior_sign(self.unit, unit)),
+            unit,
+        }
+    }
+
+    /// Left complement `A̲`, defined by `A̲ ∧ A = I`.
+    /// This is the inverse of the right complement, which is why it is also called the anti-dual.
+    pub const fn left_complement(self) -> Self {
+        let unit = self.complement_unit();
+        NonzeroBasis {
+            sign: self.sign.mul(Self::exterior_sign(unit, self.unit)),
+            unit,
+        }
+    }
+
+    /// Metric Hodge star `⋆A = Ã I`, defined by `A ∧ ⋆A = (Ã * A) I`.
+    /// Vanishes for blades containing degenerate factors.
+    pub const fn hodge(self, metric: Metric<N>) -> Basis<N> {
+        self.reverse().geometric(Self::I, metric)
+    }
+
+    /// Poincaré duality operator.
+    /// This is the Hodge star of the metric in which the degenerate dimensions square to `1`,
+    /// so it is invertible even in degenerate metrics, while it coincides with the Hodge star
+    /// wherever that one does not vanish.
+    pub const fn dual(self, metric: Metric<N>) -> Self {
+        let mut squares = metric.0;
+        repeat!(i in 0..N {
+            if let Square::Zero = squares[i] {
+                squares[i] = Square::Pos;
+            }
+        });
+        let Just(dual) = self.hodge(Metric(squares)) else {
+            unreachable!()
+        };
+        dual
+    }
+
+    /// Apply the operator named by `unary` to this blade.
+    pub const fn unary(self, unary: Unary, metric: Metric<N>) -> Basis<N> {
+        match unary {
+            Unary::Reverse => Just(self.reverse()),
+            Unary::Involute => Just(self.involute()),
+            Unary::Conjugate => Just(self.conjugate()),
+            Unary::RightComplement => Just(self.right_complement()),
+            Unary::LeftComplement => Just(self.left_complement()),
+            Unary::Hodge => self.hodge(metric),
+            Unary::Dual => Just(self.dual(metric)),
+            Unary::AntiReverse => Just(self.anti_reverse()),
+        }
+    }
+
+    /// Compute the product named by `product` between two blades.
+    pub const fn product(self, rhs: Self, product: Product, metric: Metric<N>) -> Basis<N> {
+        match product {
+            Product::Geometric => self.geometric(rhs, metric),
+            Product::Exterior => self.exterior(rhs, metric),
+            Product::Regressive => self.regressive(rhs, metric),
+            Product::LeftContraction => self.left_contraction(rhs, metric),
+            Product::RightContraction => self.right_contraction(rhs, metric),
+            Product::Inner => self.inner(rhs, metric),
+            Product::HestenesInner => self.hestenes_inner(rhs, metric),
+            Product::LeftAntiContraction => self.left_anti_contraction(rhs, metric),
+            Product::RightAntiContraction => self.right_anti_contraction(rhs, metric),
+            Product::Scalar => self.scalar(rhs, metric),
+            Product::Commutator => self.commutator(rhs, metric),
+            Product::AntiCommutator => self.anti_commutator(rhs, metric),
+            Product::AntiGeometric => self.anti_geometric(rhs, metric),
+            Product::AntiScalar => self.anti_scalar(rhs, metric),
         }
     }
 
@@ -128,9 +258,11 @@ impl<const N: usize> NonzeroBasis<N> {
     }
 
     // Compute the regressive product between two blades using the identity
-    /// `A ∨ B = J(J(A) ∧ J(B))`
+    /// `A ∨ B = (A̅ ∧ B̅)̲`, such that `I` is its identity.
     pub const fn regressive(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
-        Just(yeet!(self.dual().exterior(rhs.dual(), metric)).dual())
+        let lhs = self.right_complement();
+        let rhs = rhs.right_complement();
+        Just(yeet!(lhs.exterior(rhs, metric)).left_complement())
     }
 
     /// Contraction of `self` onto `rhs`.
@@ -154,6 +286,7 @@ impl<const N: usize> NonzeroBasis<N> {
     }
 
     /// Bi-directional contraction.
+    /// This is Dorst's scalar-preserving "fat dot", i.e. `α · B = αB` for a scalar `α`.
     pub const fn inner(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
         let product = yeet!(self.geometric(rhs, metric));
         if rhs.grade().abs_diff(self.grade()) == product.grade() {
@@ -163,9 +296,34 @@ impl<const N: usize> NonzeroBasis<N> {
         }
     }
 
+    /// Hestenes' inner product, which equals [NonzeroBasis::inner],
+    /// but vanishes if either side is a scalar.
+    pub const fn hestenes_inner(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        if self.grade() == 0 || rhs.grade() == 0 {
+            return Nothing;
+        }
+        self.inner(rhs, metric)
+    }
+
+    /// Left contraction of the complements `(A̅ ⌋ B̅)̲`, the dual of [NonzeroBasis::left_contraction]
+    /// like [NonzeroBasis::regressive] is the dual of [NonzeroBasis::exterior].
+    /// In degenerate metrics like PGA, it measures the weights that the contraction cannot.
+    pub const fn left_anti_contraction(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let lhs = self.right_complement();
+        let rhs = rhs.right_complement();
+        Just(yeet!(lhs.left_contraction(rhs, metric)).left_complement())
+    }
+
+    /// Right contraction of the complements `(A̅ ⌊ B̅)̲`, see [NonzeroBasis::left_anti_contraction].
+    pub const fn right_anti_contraction(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let lhs = self.right_complement();
+        let rhs = rhs.right_complement();
+        Just(yeet!(lhs.right_contraction(rhs, metric)).left_complement())
+    }
+
     /// Scalar product, producing non-zero scalars only when grades match.
     /// In that case, the result can be interpreted as a metric between blades:
-    /// `A~ * A` can be used as the squared norm of `A`.
+    /// `A~ * A` can be used as the squared norm of `A`, see [crate::MV::norm_squared].
     pub const fn scalar(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
         let product = yeet!(self.geometric(rhs, metric));
         if product.grade() == 0 {
@@ -175,6 +333,48 @@ impl<const N: usize> NonzeroBasis<N> {
         }
     }
 
+    /// Commutator product `(AB - BA) / 2`.
+    /// Basis blades either commute or anticommute, so this is `AB` if they anticommute
+    /// and vanishes otherwise.
+    pub const fn commutator(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let product = yeet!(self.geometric(rhs, metric));
+        let reversed = yeet!(rhs.geometric(self, metric));
+        if let Sign::Neg = product.sign.mul(reversed.sign) {
+            Just(product)
+        } else {
+            Nothing
+        }
+    }
+
+    /// Anti-commutator product `(AB + BA) / 2`, which is `AB` if the basis blades commute
+    /// and vanishes otherwise.
+    pub const fn anti_commutator(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let product = yeet!(self.geometric(rhs, metric));
+        let reversed = yeet!(rhs.geometric(self, metric));
+        if let Sign::Pos = product.sign.mul(reversed.sign) {
+            Just(product)
+        } else {
+            Nothing
+        }
+    }
+
+    /// Geometric antiproduct `A ⟇ B = (A̅ B̅)̲`, whose identity is `I` instead of `1`.
+    /// It is the dual of [NonzeroBasis::geometric] like [NonzeroBasis::regressive]
+    /// is the dual of [NonzeroBasis::exterior], and composes e.g. the motors of PGA.
+    pub const fn anti_geometric(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let lhs = self.right_complement();
+        let rhs = rhs.right_complement();
+        Just(yeet!(lhs.geometric(rhs, metric)).left_complement())
+    }
+
+    /// Anti-scalar product `(A̅ * B̅)̲`, producing non-zero multiples of `I` only when
+    /// the anti-grades match. `A ⟇ A̰` measures the weight of `A` in degenerate metrics.
+    pub const fn anti_scalar(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        let lhs = self.right_complement();
+        let rhs = rhs.right_complement();
+        Just(yeet!(lhs.scalar(rhs, metric)).left_complement())
+    }
+
     /// The *grade* (Sometimes also called *step*) of this blade, equating to the number of distinct factors.
     pub const fn grade(self) -> usize {
         let mut grade = 0;
@@ -189,6 +389,31 @@ impl<const N: usize> NonzeroBasis<N> {
     pub const fn anti_grade(self) -> usize {
         N - self.grade()
     }
+
+    /// The positively signed blade whose factors are the set bits of `index`, i.e.
+    /// `eᵢ` ⇔ `index & (1 << i) != 0`.
+    pub const fn from_index(index: usize) -> Self {
+        let mut unit = [false; N];
+        repeat!(i in 0..N {
+            unit[i] = index & (1 << i) != 0;
+        });
+        NonzeroBasis {
+            sign: Sign::Pos,
+            unit,
+        }
+    }
+
+    /// The position of this blade in a multivector's coefficient array, ignoring its sign.
+    /// Inverse of [NonzeroBasis::from_index].
+    pub const fn index(self) -> usize {
+        let mut index = 0;
+        repeat!(i in 0..N {
+            if self.unit[i] {
+                index |= 1 << i;
+            }
+        });
+        index
+    }
 }
 
 impl<const N: usize> std::fmt::Display for NonzeroBasis<N> {
@@ -248,10 +473,38 @@ impl<const N: usize> Basis<N> {
         yeet!(self).inner(yeet!(rhs), metric)
     }
 
+    pub const fn hestenes_inner(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).hestenes_inner(yeet!(rhs), metric)
+    }
+
+    pub const fn left_anti_contraction(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).left_anti_contraction(yeet!(rhs), metric)
+    }
+
+    pub const fn right_anti_contraction(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).right_anti_contraction(yeet!(rhs), metric)
+    }
+
     pub const fn scalar(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
         yeet!(self).scalar(yeet!(rhs), metric)
     }
 
+    pub const fn commutator(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).commutator(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_commutator(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).anti_commutator(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_geometric(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).anti_geometric(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_scalar(self, rhs: Self, metric: Metric<N>) -> Basis<N> {
+        yeet!(self).anti_scalar(yeet!(rhs), metric)
+    }
+
     pub const fn grade(self) -> Maybe<usize> {
         Just(yeet!(self).grade())
     }
@@ -263,4 +516,24 @@ impl<const N: usize> Basis<N> {
     pub const fn reverse(self) -> Self {
         Just(yeet!(self).reverse())
     }
+
+    pub const fn anti_reverse(self) -> Self {
+        Just(yeet!(self).anti_reverse())
+    }
+
+    pub const fn right_complement(self) -> Self {
+        Just(yeet!(self).right_complement())
+    }
+
+    pub const fn left_complement(self) -> Self {
+        Just(yeet!(self).left_complement())
+    }
+
+    pub const fn hodge(self, metric: Metric<N>) -> Self {
+        yeet!(self).hodge(metric)
+    }
+
+    pub const fn dual(self, metric: Metric<N>) -> Self {
+        Just(yeet!(self).dual(metric))
+    }
 }
@@ -0,0 +1,451 @@
+use crate::{
+    basis::{NonzeroBasis, Product},
+    common::{even, odd},
+    macros::{repeat, yeet},
+    maybe::Maybe::{self, Just, Nothing},
+    metric::{Metric, Square},
+    sign::Sign,
+};
+
+/// Encodes a [Metric] as bitmasks of its negative and degenerate dimensions,
+/// such that products can be computed with bitwise operations.
+/// Unlike [Metric], its type does not depend on the dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
+pub struct BitMetric {
+    pub dim: usize,
+    pub neg: u64,
+    pub zero: u64,
+}
+
+impl BitMetric {
+    pub const fn new<const N: usize>(metric: Metric<N>) -> Self {
+        assert!(
+            N <= u64::BITS as usize,
+            "Bitmasks support at most 64 dimensions"
+        );
+        let mut neg = 0;
+        let mut zero = 0;
+        repeat!(i in 0..N {
+            match metric.0[i] {
+                Square::Pos => {}
+                Square::Neg => neg |= 1 << i,
+                Square::Zero => zero |= 1 << i,
+            }
+        });
+        BitMetric { dim: N, neg, zero }
+    }
+
+    /// The diagonal metric with `p` positive, `q` negative and `r` degenerate dimensions,
+    /// see [Metric::signature].
+    pub const fn signature(p: usize, q: usize, r: usize) -> Self {
+        assert!(
+            p + q + r <= u64::BITS as usize,
+            "Bitmasks support at most 64 dimensions"
+        );
+        BitMetric {
+            dim: p + q + r,
+            neg: shifted_mask(q, p),
+            zero: shifted_mask(r, p + q),
+        }
+    }
+
+    /// Bitmask of the pseudoscalar, i.e. all dimensions.
+    pub const fn pseudoscalar(self) -> u64 {
+        shifted_mask(self.dim, 0)
+    }
+}
+
+/// The bitmask of `count` dimensions starting at `start`, such that all 64 dimensions
+/// can be masked without overflowing the shifts.
+const fn shifted_mask(count: usize, start: usize) -> u64 {
+    if count == 0 {
+        return 0;
+    }
+    (u64::MAX >> (u64::BITS as usize - count)) << start
+}
+
+/// Encodes the basis of a blade as a bitmask, such that `A = B eᵢ` ⇔ `A & (1 << i) != 0`.
+/// This is equivalent to [NonzeroBasis], but most operations are bitwise operations
+/// instead of loops over the dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
+pub struct NonzeroBitBasis {
+    pub sign: Sign,
+    pub unit: u64,
+}
+
+/// A bitmask blade basis which can also vanish, see [crate::basis::Basis].
+pub type BitBasis = Maybe<NonzeroBitBasis>;
+
+/// The sign caused by reordering the factors of the geometric product `lhs rhs`
+/// into canonical order: Each factor of `lhs` has to be swapped with every factor
+/// of `rhs` with a lower index.
+/// The parity of these swaps is computed by masking `lhs` with the prefix parity of `rhs`.
+pub const fn reordering_sign(lhs: u64, rhs: u64) -> Sign {
+    // Bit `i` of `lower` is the parity of the factors of `rhs` with an index lower than `i`.
+    let mut lower = rhs << 1;
+    let mut shift = 1;
+    while shift < u64::BITS {
+        lower ^= lower << shift;
+        shift <<= 1;
+    }
+    if even((lhs & lower).count_ones() as usize) {
+        Sign::Pos
+    } else {
+        Sign::Neg
+    }
+}
+
+impl NonzeroBitBasis {
+    pub const ONE: NonzeroBitBasis = NonzeroBitBasis {
+        sign: Sign::Pos,
+        unit: 0,
+    };
+
+    /// Lossless conversion from the array encoding.
+    pub const fn from_array<const N: usize>(basis: NonzeroBasis<N>) -> Self {
+        assert!(
+            N <= u64::BITS as usize,
+            "Bitmasks support at most 64 dimensions"
+        );
+        NonzeroBitBasis {
+            sign: basis.sign,
+            unit: basis.index() as u64,
+        }
+    }
+
+    /// Lossless conversion into the array encoding.
+    /// Panics if this blade has factors outside of the `N` dimensions.
+    pub const fn to_array<const N: usize>(self) -> NonzeroBasis<N> {
+        assert!(
+            N >= u64::BITS as usize || self.unit >> N == 0,
+            "The blade does not fit into the dimension"
+        );
+        let basis = NonzeroBasis::from_index(self.unit as usize);
+        NonzeroBasis {
+            sign: self.sign,
+            unit: basis.unit,
+        }
+    }
+
+    pub const fn grade(self) -> usize {
+        self.unit.count_ones() as usize
+    }
+
+    pub const fn anti_grade(self, metric: BitMetric) -> usize {
+        metric.dim - self.grade()
+    }
+
+    pub const fn neg(self) -> Self {
+        NonzeroBitBasis {
+            sign: self.sign.neg(),
+            unit: self.unit,
+        }
+    }
+
+    /// See [NonzeroBasis::reverse].
+    pub const fn reverse(self) -> Self {
+        let r = self.grade();
+        if r > 0 && odd(r * (r - 1) / 2) {
+            self.neg()
+        } else {
+            self
+        }
+    }
+
+    /// See [NonzeroBasis::involute].
+    pub const fn involute(self) -> Self {
+        if even(self.grade()) { self } else { self.neg() }
+    }
+
+    /// See [NonzeroBasis::conjugate].
+    pub const fn conjugate(self) -> Self {
+        self.reverse().involute()
+    }
+
+    /// See [NonzeroBasis::anti_reverse].
+    pub const fn anti_reverse(self, metric: BitMetric) -> Self {
+        self.right_complement(metric)
+            .reverse()
+            .left_complement(metric)
+    }
+
+    /// See [NonzeroBasis::right_complement].
+    pub const fn right_complement(self, metric: BitMetric) -> Self {
+        let unit = !self.unit & metric.pseudoscalar();
+        NonzeroBitBasis {
+            sign: self.sign.mul(reordering_sign(self.unit, unit)),
+            unit,
+        }
+    }
+
+    /// See [NonzeroBasis::left_complement].
+    pub const fn left_complement(self, metric: BitMetric) -> Self {
+        let unit = !self.unit & metric.pseudoscalar();
+        NonzeroBitBasis {
+            sign: self.sign.mul(reordering_sign(unit, self.unit)),
+            unit,
+        }
+    }
+
+    /// See [NonzeroBasis::hodge].
+    pub const fn hodge(self, metric: BitMetric) -> BitBasis {
+        let pseudoscalar = NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit: metric.pseudoscalar(),
+        };
+        self.reverse().geometric(pseudoscalar, metric)
+    }
+
+    /// See [NonzeroBasis::dual].
+    pub const fn dual(self, metric: BitMetric) -> Self {
+        let completed = BitMetric { zero: 0, ..metric };
+        let Just(dual) = self.hodge(completed) else {
+            unreachable!()
+        };
+        dual
+    }
+
+    /// See [NonzeroBasis::product].
+    pub const fn product(self, rhs: Self, product: Product, metric: BitMetric) -> BitBasis {
+        match product {
+            Product::Geometric => self.geometric(rhs, metric),
+            Product::Exterior => self.exterior(rhs, metric),
+            Product::Regressive => self.regressive(rhs, metric),
+            Product::LeftContraction => self.left_contraction(rhs, metric),
+            Product::RightContraction => self.right_contraction(rhs, metric),
+            Product::Inner => self.inner(rhs, metric),
+            Product::HestenesInner => self.hestenes_inner(rhs, metric),
+            Product::LeftAntiContraction => self.left_anti_contraction(rhs, metric),
+            Product::RightAntiContraction => self.right_anti_contraction(rhs, metric),
+            Product::Scalar => self.scalar(rhs, metric),
+            Product::Commutator => self.commutator(rhs, metric),
+            Product::AntiCommutator => self.anti_commutator(rhs, metric),
+            Product::AntiGeometric => self.anti_geometric(rhs, metric),
+            Product::AntiScalar => self.anti_scalar(rhs, metric),
+        }
+    }
+
+    /// Compute the geometric product between two blades.
+    /// The unit of the product consists of the factors which are not shared,
+    /// while the shared factors contract according to the metric.
+    pub const fn geometric(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let shared = self.unit & rhs.unit;
+        if shared & metric.zero != 0 {
+            return Nothing;
+        }
+        let mut sign = self
+            .sign
+            .mul(rhs.sign)
+            .mul(reordering_sign(self.unit, rhs.unit));
+        if odd((shared & metric.neg).count_ones() as usize) {
+            sign = sign.neg();
+        }
+        Just(NonzeroBitBasis {
+            sign,
+            unit: self.unit ^ rhs.unit,
+        })
+    }
+
+    /// See [NonzeroBasis::exterior].
+    pub const fn exterior(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.unit & rhs.unit != 0 {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::regressive].
+    pub const fn regressive(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let lhs = self.right_complement(metric);
+        let rhs = rhs.right_complement(metric);
+        Just(yeet!(lhs.exterior(rhs, metric)).left_complement(metric))
+    }
+
+    /// See [NonzeroBasis::left_contraction].
+    pub const fn left_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.unit & !rhs.unit != 0 {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::right_contraction].
+    pub const fn right_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if rhs.unit & !self.unit != 0 {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::inner].
+    pub const fn inner(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.unit & !rhs.unit != 0 && rhs.unit & !self.unit != 0 {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::hestenes_inner].
+    pub const fn hestenes_inner(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.unit == 0 || rhs.unit == 0 {
+            return Nothing;
+        }
+        self.inner(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::left_anti_contraction].
+    pub const fn left_anti_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let lhs = self.right_complement(metric);
+        let rhs = rhs.right_complement(metric);
+        Just(yeet!(lhs.left_contraction(rhs, metric)).left_complement(metric))
+    }
+
+    /// See [NonzeroBasis::right_anti_contraction].
+    pub const fn right_anti_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let lhs = self.right_complement(metric);
+        let rhs = rhs.right_complement(metric);
+        Just(yeet!(lhs.right_contraction(rhs, metric)).left_complement(metric))
+    }
+
+    /// See [NonzeroBasis::scalar].
+    pub const fn scalar(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.unit != rhs.unit {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::anti_geometric].
+    pub const fn anti_geometric(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let lhs = self.right_complement(metric);
+        let rhs = rhs.right_complement(metric);
+        Just(yeet!(lhs.geometric(rhs, metric)).left_complement(metric))
+    }
+
+    /// See [NonzeroBasis::anti_scalar].
+    pub const fn anti_scalar(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        let lhs = self.right_complement(metric);
+        let rhs = rhs.right_complement(metric);
+        Just(yeet!(lhs.scalar(rhs, metric)).left_complement(metric))
+    }
+
+    /// Whether `self rhs = rhs self`, which only depends on the reordering signs,
+    /// as the metric contracts the shared factors the same way in both orders.
+    const fn commutes(self, rhs: Self) -> bool {
+        let forward = reordering_sign(self.unit, rhs.unit);
+        let backward = reordering_sign(rhs.unit, self.unit);
+        matches!(forward.mul(backward), Sign::Pos)
+    }
+
+    /// See [NonzeroBasis::commutator].
+    pub const fn commutator(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if self.commutes(rhs) {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+
+    /// See [NonzeroBasis::anti_commutator].
+    pub const fn anti_commutator(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        if !self.commutes(rhs) {
+            return Nothing;
+        }
+        self.geometric(rhs, metric)
+    }
+}
+
+impl BitBasis {
+    /// The positively signed blade with the factors `unit`.
+    pub const fn unit(unit: u64) -> Self {
+        Just(NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit,
+        })
+    }
+
+    pub const fn neg(self) -> Self {
+        Just(yeet!(self).neg())
+    }
+
+    pub const fn geometric(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).geometric(yeet!(rhs), metric)
+    }
+
+    pub const fn exterior(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).exterior(yeet!(rhs), metric)
+    }
+
+    pub const fn regressive(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).regressive(yeet!(rhs), metric)
+    }
+
+    pub const fn left_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).left_contraction(yeet!(rhs), metric)
+    }
+
+    pub const fn right_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).right_contraction(yeet!(rhs), metric)
+    }
+
+    pub const fn inner(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).inner(yeet!(rhs), metric)
+    }
+
+    pub const fn hestenes_inner(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).hestenes_inner(yeet!(rhs), metric)
+    }
+
+    pub const fn left_anti_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).left_anti_contraction(yeet!(rhs), metric)
+    }
+
+    pub const fn right_anti_contraction(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).right_anti_contraction(yeet!(rhs), metric)
+    }
+
+    pub const fn scalar(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).scalar(yeet!(rhs), metric)
+    }
+
+    pub const fn commutator(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).commutator(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_commutator(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).anti_commutator(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_geometric(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).anti_geometric(yeet!(rhs), metric)
+    }
+
+    pub const fn anti_scalar(self, rhs: Self, metric: BitMetric) -> BitBasis {
+        yeet!(self).anti_scalar(yeet!(rhs), metric)
+    }
+
+    pub const fn reverse(self) -> Self {
+        Just(yeet!(self).reverse())
+    }
+
+    pub const fn anti_reverse(self, metric: BitMetric) -> Self {
+        Just(yeet!(self).anti_reverse(metric))
+    }
+
+    pub const fn involute(self) -> Self {
+        Just(yeet!(self).involute())
+    }
+
+    pub const fn right_complement(self, metric: BitMetric) -> Self {
+        Just(yeet!(self).right_complement(metric))
+    }
+
+    pub const fn left_complement(self, metric: BitMetric) -> Self {
+        Just(yeet!(self).left_complement(metric))
+    }
+
+    pub const fn dual(self, metric: BitMetric) -> Self {
+        Just(yeet!(self).dual(metric))
+    }
+}
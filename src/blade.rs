@@ -1,10 +1,19 @@
-use crate::{basis::Basis, maybe::Maybe::Just, metric::Metric};
+use std::marker::PhantomData;
 
-const N: usize = 2;
+use crate::{
+    algebra::Signature,
+    bitmask::BitBasis,
+    error::Error,
+    maybe::Maybe::Just,
+    scalar::{Float, Scalar},
+};
 
-/// A blade whose type is parametrized over its basis (e.g. `e0`) and its metric.
+/// A blade whose type is parametrized over its algebra and its basis (e.g. `e0`).
+/// Products compute the basis of their result at compile time.
+/// The coefficient is of the [Scalar] type `S`.
 ///
-/// Its implementation is blocked by
+/// The basis is encoded as a [BitBasis] rather than a [crate::basis::Basis], because the type of
+/// the latter depends on the dimension of the algebra. Using it would require
 /// https://github.com/rust-lang/project-const-generics/issues/28#issue-1178177928
 /// which would enable generic const parameter types like this:
 /// ```compile_fail
@@ -12,79 +21,298 @@ const N: usize = 2;
 ///     ARR
 /// }
 /// ```
-#[derive(Debug, Clone, Copy)]
-struct Blade<const B: Basis<N>, const M: Metric<N>>(pub f64);
+pub struct Blade<A: Signature, const B: BitBasis, S = f64>(pub S, pub PhantomData<A>);
 
-impl<const B: Basis<N>, const M: Metric<N>> Blade<B, M> {
-    pub const ZERO: Self = Blade(0.0);
-    pub const ONE: Self = Blade(1.0);
+impl<A: Signature, const B: BitBasis, S: Scalar> Blade<A, B, S> {
+    pub const ZERO: Self = Blade::new(S::ZERO);
+    pub const ONE: Self = Blade::new(S::ONE);
 
-    pub fn scale(self) -> f64 {
-        let Just(basis) = B else { return 0.0 };
+    pub const fn new(value: S) -> Self {
+        Blade(value, PhantomData)
+    }
+
+    pub fn scale(self) -> S {
+        let Just(basis) = B else { return S::ZERO };
         basis.sign * self.0
     }
 
-    pub fn geometric<const T: Basis<N>>(self, rhs: Blade<T, M>) -> Blade<{ B.geometric(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+    pub fn geometric<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.geometric(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn exterior<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.exterior(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn regressive<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.regressive(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn left_contraction<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.left_contraction(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn right_contraction<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.right_contraction(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn inner<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.inner(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn hestenes_inner<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.hestenes_inner(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn left_anti_contraction<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.left_anti_contraction(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn right_anti_contraction<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.right_anti_contraction(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
     }
 
-    pub fn exterior<const T: Basis<N>>(self, rhs: Blade<T, M>) -> Blade<{ B.exterior(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+    pub fn scalar<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.scalar(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
     }
 
-    pub fn left_contraction<const T: Basis<N>>(
+    pub fn commutator<const T: BitBasis>(
         self,
-        rhs: Blade<T, M>,
-    ) -> Blade<{ B.left_contraction(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.commutator(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
     }
 
-    pub fn right_contraction<const T: Basis<N>>(
+    pub fn anti_commutator<const T: BitBasis>(
         self,
-        rhs: Blade<T, M>,
-    ) -> Blade<{ B.right_contraction(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.anti_commutator(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn anti_geometric<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.anti_geometric(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn anti_scalar<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Blade<A, { B.anti_scalar(T, A::BIT_METRIC) }, S> {
+        Blade::new(self.0 * rhs.0)
+    }
+
+    pub fn reverse(self) -> Blade<A, { B.reverse() }, S> {
+        Blade::new(self.0)
+    }
+
+    pub fn anti_reverse(self) -> Blade<A, { B.anti_reverse(A::BIT_METRIC) }, S> {
+        Blade::new(self.0)
     }
 
-    pub fn inner<const T: Basis<N>>(self, rhs: Blade<T, M>) -> Blade<{ B.inner(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+    pub fn dual(self) -> Blade<A, { B.dual(A::BIT_METRIC) }, S> {
+        Blade::new(self.0)
     }
+}
 
-    pub fn scalar<const T: Basis<N>>(self, rhs: Blade<T, M>) -> Blade<{ B.scalar(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+impl<A: Signature, const B: BitBasis, S: Float> Blade<A, B, S> {
+    /// The inverse `A⁻¹ = A / (A A)`, which has the same basis, as `A A` is a scalar.
+    /// Fails for zero and null blades, which square to zero.
+    pub fn inverse(self) -> Result<Self, Error> {
+        let Just(square) = B.geometric(B, A::BIT_METRIC) else {
+            return Err(Error::NotInvertible);
+        };
+        // A single coefficient is free of rounding errors relative to its own magnitude,
+        // such that only zero has no inverse.
+        if self.0 == S::ZERO {
+            return Err(Error::NotInvertible);
+        }
+        Ok(Blade::new(square.sign * (S::ONE / self.0)))
     }
 
-    // pub fn norm_squared(self) -> f64
-    // where
-    //     [;{S.scalar(S, M)}]:,
-    // {
-    //     self.scalar(self);
-    //     todo!()
-    // }
+    /// Computes `A` projected onto `B` using the formula `(A >> B) >> B⁻¹`.
+    /// Fails if `B` is not invertible, e.g. for null blades of degenerate metrics.
+    pub fn project<const T: BitBasis>(
+        self,
+        rhs: Blade<A, T, S>,
+    ) -> Result<
+        Blade<
+            A,
+            {
+                B.left_contraction(T, A::BIT_METRIC)
+                    .left_contraction(T, A::BIT_METRIC)
+            },
+            S,
+        >,
+        Error,
+    > {
+        let inverse = rhs.inverse()?;
+        Ok(Blade::new(self.0 * rhs.0 * inverse.0))
+    }
 
-    /// Computes `A` projected onto `B` using the formular `(A >> B^-1) >> B`.
-    /// TODO: Currently, this ignores the norm of [rhs] in `B^-1`.
-    /// Multivectors using this operation either need to scale the result by [rhs]'s reciprocal norm,
-    /// or normalize [rhs] before projecting.
-    pub fn project<const T: Basis<N>>(
+    /// Computes the rejection of `A` from `B` using the formula `(A ^ B) << B⁻¹`,
+    /// i.e. the part of a vector `A` which is orthogonal to `B`.
+    /// Fails if `B` is not invertible, e.g. for null blades of degenerate metrics.
+    pub fn reject<const T: BitBasis>(
         self,
-        rhs: Blade<T, M>,
-    ) -> Blade<{ B.left_contraction(T, M).reverse().left_contraction(T, M) }, M> {
-        Blade(self.0 * rhs.0)
+        rhs: Blade<A, T, S>,
+    ) -> Result<
+        Blade<
+            A,
+            {
+                B.exterior(T, A::BIT_METRIC)
+                    .right_contraction(T, A::BIT_METRIC)
+            },
+            S,
+        >,
+        Error,
+    > {
+        let inverse = rhs.inverse()?;
+        Ok(Blade::new(self.0 * rhs.0 * inverse.0))
+    }
+}
+
+// Deriving these would require `A` to implement them.
+
+impl<A: Signature, const B: BitBasis, S: Scalar> Clone for Blade<A, B, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Signature, const B: BitBasis, S: Scalar> Copy for Blade<A, B, S> {}
+
+impl<A: Signature, const B: BitBasis, S: Scalar> std::fmt::Debug for Blade<A, B, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Blade").field(&B).field(&self.0).finish()
+    }
+}
+
+/// `A * B`: Geometric product
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::Mul<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.geometric(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.geometric(T, A::BIT_METRIC) }, S>;
+
+    fn mul(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.geometric(rhs)
+    }
+}
+
+/// `A ^ B`: Exterior product
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::BitXor<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.exterior(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.exterior(T, A::BIT_METRIC) }, S>;
+
+    fn bitxor(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.exterior(rhs)
     }
 }
 
-// This does not work because we cannot be generic over `rhs`:
-// impl<const S: Basis<N>, const T: Basis<N>, const M: Metric<N>> std::ops::Mul for Blade<S, M> {
-//     type Output = Blade<{ S.geometric(T, M) }, M>;
-//     fn mul(self, rhs: Blade<T, M>) -> Self::Output {
-//         Blade(self.0 * rhs.0)
-//     }
-// }
+/// `A & B`: Regressive product
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::BitAnd<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.regressive(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.regressive(T, A::BIT_METRIC) }, S>;
+
+    fn bitand(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.regressive(rhs)
+    }
+}
+
+/// `A | B`: Inner product
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::BitOr<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.inner(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.inner(T, A::BIT_METRIC) }, S>;
+
+    fn bitor(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.inner(rhs)
+    }
+}
+
+/// `A >> B`: Contraction of `A` onto `B`
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::Shr<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.left_contraction(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.left_contraction(T, A::BIT_METRIC) }, S>;
+
+    fn shr(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.left_contraction(rhs)
+    }
+}
+
+/// `A << B`: Contraction of `A` by `B`
+impl<A: Signature, const B: BitBasis, const T: BitBasis, S: Scalar> std::ops::Shl<Blade<A, T, S>>
+    for Blade<A, B, S>
+where
+    Blade<A, { B.right_contraction(T, A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.right_contraction(T, A::BIT_METRIC) }, S>;
+
+    fn shl(self, rhs: Blade<A, T, S>) -> Self::Output {
+        self.right_contraction(rhs)
+    }
+}
+
+/// `!A`: Poincaré dual
+impl<A: Signature, const B: BitBasis, S: Scalar> std::ops::Not for Blade<A, B, S>
+where
+    Blade<A, { B.dual(A::BIT_METRIC) }, S>:,
+{
+    type Output = Blade<A, { B.dual(A::BIT_METRIC) }, S>;
+
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
 
-impl<const S: Basis<{ N }>, const M: Metric<{ N }>> std::ops::Neg for Blade<S, M> {
+impl<A: Signature, const B: BitBasis, S: Scalar> std::ops::Neg for Blade<A, B, S> {
     fn neg(self) -> Self {
-        Blade(-self.0)
+        Blade::new(-self.0)
     }
     type Output = Self;
 }
@@ -0,0 +1,39 @@
+use crate::{
+    basis::{NonzeroBasis, Product},
+    macros::repeat,
+    maybe::Maybe::{self, Just, Nothing},
+    metric::Metric,
+    sign::Sign,
+};
+
+/// The product of two basis blades, given as the index of the resulting blade and its sign.
+/// Indices are those of [NonzeroBasis::index].
+pub type Entry = Maybe<(usize, Sign)>;
+
+/// Tabulates a product for all pairs of basis blades of an algebra with `N` basis blades,
+/// such that `table.0[i][j]` is the product of the blades with the indices `i` and `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CayleyTable<const N: usize>(pub [[Entry; N]; N]);
+
+impl<const N: usize> CayleyTable<N> {
+    /// Evaluates `product` for every pair of basis blades of the metric's algebra.
+    /// `N` must equal the number of basis blades, i.e. `2ᴰ`.
+    pub const fn new<const D: usize>(product: Product, metric: Metric<D>) -> Self {
+        assert!(
+            N == 1 << D,
+            "The table size must match the number of basis blades"
+        );
+        let mut table = [[Nothing; N]; N];
+        repeat!(i in 0..N {
+            repeat!(j in 0..N {
+                let lhs = NonzeroBasis::<D>::from_index(i);
+                let rhs = NonzeroBasis::<D>::from_index(j);
+                table[i][j] = match lhs.product(rhs, product, metric) {
+                    Just(basis) => Just((basis.index(), basis.sign)),
+                    Nothing => Nothing,
+                };
+            });
+        });
+        CayleyTable(table)
+    }
+}
@@ -1,14 +1,13 @@
+// The partial products `n (n - 1) ... (n - i + 1) / i!` are the binomials `(n choose i)`,
+// such that only their product with `n - i` may exceed `usize`.
 pub const fn binomial(n: usize, k: usize) -> usize {
-    factorial(n) / (factorial(k) * factorial(n - k))
-}
-
-// TODO: Maybe use [std::num::NonZeroUsize] as the return type?
-pub const fn factorial(n: usize) -> usize {
-    if n == 0 {
-        1
-    } else {
-        n * factorial(n - 1)
+    let mut result: u128 = 1;
+    let mut i = 0;
+    while i < k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        i += 1;
     }
+    result as usize
 }
 
 pub const fn pow(n: usize, mut k: usize) -> usize {
@@ -20,14 +19,11 @@ pub const fn pow(n: usize, mut k: usize) -> usize {
     result
 }
 
+#[cfg(feature = "nightly")]
 pub fn concat<const N: usize, const M: usize>(a: [i32; N], b: [i32; M]) -> [i32; N + M] {
     let mut result = [0; N + M];
-    for i in 0..N {
-        result[i] = a[i];
-    }
-    for i in 0..M {
-        result[N + i] = b[i];
-    }
+    result[..N].copy_from_slice(&a);
+    result[N..].copy_from_slice(&b);
     result
 }
 
@@ -0,0 +1,34 @@
+/// The errors of fallible multivector operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The element has no inverse, e.g. because it is a null vector like `e3` in [crate::PGA3].
+    NotInvertible,
+    /// The element is not the exponential of a bivector, or its logarithm is ambiguous,
+    /// e.g. for rotations by `π`.
+    NoLogarithm,
+    /// The norm vanishes, such that the element cannot be normalized,
+    /// e.g. null vectors of [crate::CGA3] or ideal points of [crate::PGA3].
+    ZeroNorm,
+    /// The element is not a versor, i.e. not a product of invertible vectors.
+    NotVersor,
+    /// The operation requires a non-degenerate metric, e.g. reflections, which do not exist
+    /// for the null vectors of [crate::PGA3].
+    DegenerateMetric,
+    /// The matrix of a [crate::BilinearForm] is not symmetric.
+    NotSymmetric,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::NotInvertible => write!(f, "the multivector is not invertible"),
+            Error::NoLogarithm => write!(f, "the multivector has no unique logarithm"),
+            Error::ZeroNorm => write!(f, "the norm of the multivector vanishes"),
+            Error::NotVersor => write!(f, "the multivector is not a versor"),
+            Error::DegenerateMetric => write!(f, "the metric of the algebra is degenerate"),
+            Error::NotSymmetric => write!(f, "the bilinear form is not symmetric"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,356 @@
+use crate::{
+    algebra::Signature,
+    basis::Product,
+    bitmask::{NonzeroBitBasis, reordering_sign},
+    error::Error,
+    metric::{Metric, Square},
+    scalar::Float,
+    sign::Sign,
+    sparse::SparseMV,
+};
+
+/// A metric given by an arbitrary symmetric bilinear form `eᵢ·eⱼ = matrix[i][j]`,
+/// unlike the diagonal [Metric], e.g. the [BilinearForm::conformal] one with a null basis.
+///
+/// Multivectors are [SparseMV]s whose coefficients refer to the blades `eᵢ ∧ eⱼ ∧ …`, as the
+/// geometric products `eᵢ eⱼ …` are no blades for non-orthogonal vectors. Products transform
+/// both factors to the orthonormal eigenvectors of the matrix, where the bitmask products apply,
+/// and transform the result back. The [Signature] of the [SparseMV] only provides its dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BilinearForm<const N: usize, T = f64> {
+    matrix: [[T; N]; N],
+    /// The columns are the orthonormal eigenvectors `fⱼ = Σᵢ basis[i][j] eᵢ`.
+    basis: [[T; N]; N],
+    /// The eigenvalues `fⱼ·fⱼ`, with the negligible ones being exactly zero.
+    squares: [T; N],
+}
+
+impl<const N: usize, T: Float> BilinearForm<N, T> {
+    pub fn new(matrix: [[T; N]; N]) -> Result<Self, Error> {
+        if (0..N).any(|i| (0..i).any(|j| matrix[i][j] != matrix[j][i])) {
+            return Err(Error::NotSymmetric);
+        }
+        let (basis, squares) = diagonalize(matrix);
+        Ok(BilinearForm {
+            matrix,
+            basis,
+            squares,
+        })
+    }
+
+    pub fn from_metric(metric: Metric<N>) -> Self {
+        let mut matrix = [[T::ZERO; N]; N];
+        for (i, square) in metric.0.into_iter().enumerate() {
+            matrix[i][i] = match square {
+                Square::Pos => T::ONE,
+                Square::Neg => -T::ONE,
+                Square::Zero => T::ZERO,
+            };
+        }
+        BilinearForm {
+            matrix,
+            basis: identity(),
+            squares: std::array::from_fn(|i| matrix[i][i]),
+        }
+    }
+
+    /// The conformal metric of the `N - 2` dimensional Euclidean space, whose last two basis
+    /// vectors are the null vectors [BilinearForm::origin] and [BilinearForm::infinity]
+    /// with `e₀·e∞ = -1`.
+    pub fn conformal() -> Self {
+        assert!(
+            N >= 2,
+            "The conformal metric requires two additional dimensions"
+        );
+        let mut matrix = [[T::ZERO; N]; N];
+        for (i, row) in matrix.iter_mut().enumerate().take(N - 2) {
+            row[i] = T::ONE;
+        }
+        matrix[N - 2][N - 1] = -T::ONE;
+        matrix[N - 1][N - 2] = -T::ONE;
+        let (basis, squares) = diagonalize(matrix);
+        BilinearForm {
+            matrix,
+            basis,
+            squares,
+        }
+    }
+
+    /// The null vector `e₀` of the [BilinearForm::conformal] metric.
+    pub const fn origin() -> NonzeroBitBasis {
+        NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit: 1 << (N - 2),
+        }
+    }
+
+    /// The null vector `e∞` of the [BilinearForm::conformal] metric.
+    pub const fn infinity() -> NonzeroBitBasis {
+        NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit: 1 << (N - 1),
+        }
+    }
+
+    pub fn matrix(&self) -> [[T; N]; N] {
+        self.matrix
+    }
+
+    /// `u·v` of the vectors with the coefficients `u` and `v`.
+    pub fn apply(&self, u: [T; N], v: [T; N]) -> T {
+        u.into_iter()
+            .zip(self.matrix)
+            .fold(T::ZERO, |sum, (u, row)| {
+                row.into_iter()
+                    .zip(v)
+                    .fold(sum, |sum, (entry, v)| sum + u * entry * v)
+            })
+    }
+
+    /// Computes the products like [SparseMV::product], but with this metric instead of
+    /// the one of `A`. The complements of the anti-products and the regressive product refer
+    /// to the pseudoscalar `e₀ ∧ e₁ ∧ …`.
+    pub fn product<A: Signature>(
+        &self,
+        lhs: &SparseMV<A, T>,
+        rhs: &SparseMV<A, T>,
+        product: Product,
+    ) -> SparseMV<A, T> {
+        assert_eq!(
+            A::DIM,
+            N,
+            "The dimensions of the algebra and the metric differ"
+        );
+        let half = T::ONE / (T::ONE + T::ONE);
+        let complement = |base| {
+            let lhs = map_basis(lhs, |basis| basis.right_complement(A::BIT_METRIC));
+            let rhs = map_basis(rhs, |basis| basis.right_complement(A::BIT_METRIC));
+            map_basis(&self.product(&lhs, &rhs, base), |basis| {
+                basis.left_complement(A::BIT_METRIC)
+            })
+        };
+        match product {
+            Product::Geometric => self.geometric(lhs, rhs),
+            Product::Commutator => {
+                (self.geometric(lhs, rhs) - self.geometric(rhs, lhs)).scale(half)
+            }
+            Product::AntiCommutator => {
+                (self.geometric(lhs, rhs) + self.geometric(rhs, lhs)).scale(half)
+            }
+            Product::Regressive => complement(Product::Exterior),
+            Product::LeftAntiContraction => complement(Product::LeftContraction),
+            Product::RightAntiContraction => complement(Product::RightContraction),
+            Product::AntiGeometric => complement(Product::Geometric),
+            Product::AntiScalar => complement(Product::Scalar),
+            _ => {
+                // The remaining products select a grade of the geometric product, which
+                // only depends on the grades of the factors.
+                let mut result = SparseMV::ZERO;
+                for r in 0..=N {
+                    for s in 0..=N {
+                        let (lhs, rhs) = (lhs.grade(r), rhs.grade(s));
+                        if lhs.is_empty() || rhs.is_empty() {
+                            continue;
+                        }
+                        if let Some(k) = selected_grade(product, r, s) {
+                            result = result + self.geometric(&lhs, &rhs).grade(k);
+                        }
+                    }
+                }
+                result
+            }
+        }
+    }
+
+    pub fn geometric<A: Signature>(
+        &self,
+        lhs: &SparseMV<A, T>,
+        rhs: &SparseMV<A, T>,
+    ) -> SparseMV<A, T> {
+        assert_eq!(
+            A::DIM,
+            N,
+            "The dimensions of the algebra and the metric differ"
+        );
+        // `eᵢ = Σⱼ basis[i][j] fⱼ`, as the basis is orthogonal.
+        let to_eigen = |i: usize| self.basis[i];
+        let from_eigen = |j: usize| -> [T; N] { std::array::from_fn(|i| self.basis[i][j]) };
+
+        let lhs_eigen = outermorphism(lhs, to_eigen);
+        let rhs_eigen = outermorphism(rhs, to_eigen);
+        let mut product = SparseMV::<A, T>::ZERO;
+        for (a, x) in lhs_eigen.iter() {
+            for (b, y) in rhs_eigen.iter() {
+                let shared = a.unit & b.unit;
+                let square = (0..N)
+                    .filter(|j| shared & (1 << j) != 0)
+                    .fold(T::ONE, |square, j| square * self.squares[j]);
+                let basis = NonzeroBitBasis {
+                    sign: reordering_sign(a.unit, b.unit),
+                    unit: a.unit ^ b.unit,
+                };
+                product = product + SparseMV::blade(x * y * square, basis);
+            }
+        }
+
+        // Remove the rounding errors of the change of basis.
+        let scale = self.squares.iter().fold(T::ONE, |max, &square| {
+            if square.abs() > max {
+                square.abs()
+            } else {
+                max
+            }
+        });
+        let tolerance = l1_norm(lhs) * l1_norm(rhs) * scale * dimension::<T>(N) * T::EPSILON;
+        outermorphism(&product, from_eigen)
+            .iter()
+            .filter(|(_, value)| value.abs() > tolerance)
+            .fold(SparseMV::ZERO, |result, (basis, value)| {
+                result + SparseMV::blade(value, basis)
+            })
+    }
+
+    pub fn left_contraction<A: Signature>(
+        &self,
+        lhs: &SparseMV<A, T>,
+        rhs: &SparseMV<A, T>,
+    ) -> SparseMV<A, T> {
+        self.product(lhs, rhs, Product::LeftContraction)
+    }
+
+    pub fn right_contraction<A: Signature>(
+        &self,
+        lhs: &SparseMV<A, T>,
+        rhs: &SparseMV<A, T>,
+    ) -> SparseMV<A, T> {
+        self.product(lhs, rhs, Product::RightContraction)
+    }
+
+    pub fn inner<A: Signature>(
+        &self,
+        lhs: &SparseMV<A, T>,
+        rhs: &SparseMV<A, T>,
+    ) -> SparseMV<A, T> {
+        self.product(lhs, rhs, Product::Inner)
+    }
+
+    pub fn scalar_product<A: Signature>(&self, lhs: &SparseMV<A, T>, rhs: &SparseMV<A, T>) -> T {
+        self.product(lhs, rhs, Product::Scalar)
+            .get(NonzeroBitBasis::ONE)
+    }
+}
+
+/// The grade which the products built from grade selections keep of the geometric product
+/// of a grade `r` and a grade `s` blade, which are the bitmask conditions for orthogonal bases.
+fn selected_grade(product: Product, r: usize, s: usize) -> Option<usize> {
+    match product {
+        Product::Exterior => Some(r + s),
+        Product::LeftContraction => s.checked_sub(r),
+        Product::RightContraction => r.checked_sub(s),
+        Product::Inner => Some(r.abs_diff(s)),
+        Product::HestenesInner if r > 0 && s > 0 => Some(r.abs_diff(s)),
+        Product::Scalar => Some(0),
+        _ => None,
+    }
+}
+
+/// Applies the linear map of the vectors `eᵢ ↦ Σⱼ image(i)[j] eⱼ` to the blades.
+fn outermorphism<A: Signature, T: Float, const N: usize>(
+    mv: &SparseMV<A, T>,
+    image: impl Fn(usize) -> [T; N],
+) -> SparseMV<A, T> {
+    let mut result = SparseMV::ZERO;
+    for (basis, value) in mv.iter() {
+        let mut blade = SparseMV::scalar(value);
+        for i in (0..N).filter(|i| basis.unit & (1 << i) != 0) {
+            let vector = image(i).into_iter().enumerate().fold(
+                SparseMV::ZERO,
+                |vector, (j, coefficient)| {
+                    let basis = NonzeroBitBasis {
+                        sign: Sign::Pos,
+                        unit: 1 << j,
+                    };
+                    vector + SparseMV::blade(coefficient, basis)
+                },
+            );
+            // The exterior product does not depend on the metric of `A`.
+            blade = blade.exterior(&vector);
+        }
+        result = result + blade;
+    }
+    result
+}
+
+fn map_basis<A: Signature, T: Float>(
+    mv: &SparseMV<A, T>,
+    map: impl Fn(NonzeroBitBasis) -> NonzeroBitBasis,
+) -> SparseMV<A, T> {
+    mv.iter().fold(SparseMV::ZERO, |result, (basis, value)| {
+        result + SparseMV::blade(value, map(basis))
+    })
+}
+
+fn l1_norm<A: Signature, T: Float>(mv: &SparseMV<A, T>) -> T {
+    mv.iter().fold(T::ZERO, |sum, (_, value)| sum + value.abs())
+}
+
+fn dimension<T: Float>(n: usize) -> T {
+    (0..n).fold(T::ZERO, |sum, _| sum + T::ONE)
+}
+
+fn identity<T: Float, const N: usize>() -> [[T; N]; N] {
+    std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }))
+}
+
+/// The cyclic Jacobi eigenvalue algorithm: Each sweep rotates the planes of all off-diagonal
+/// entries, until the matrix is diagonal up to rounding errors.
+/// Returns the rotated basis as columns and the eigenvalues.
+fn diagonalize<T: Float, const N: usize>(matrix: [[T; N]; N]) -> ([[T; N]; N], [T; N]) {
+    let scale =
+        matrix.iter().flatten().fold(
+            T::ZERO,
+            |max, &entry| {
+                if entry.abs() > max { entry.abs() } else { max }
+            },
+        );
+    let tolerance = scale * T::EPSILON;
+    let (mut a, mut v) = (matrix, identity());
+
+    for _ in 0..64 {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q].abs() <= tolerance {
+                    continue;
+                }
+                rotated = true;
+                // Rotates by the angle which eliminates `a[p][q]`, choosing the smaller one.
+                let theta = (a[q][q] - a[p][p]) / ((T::ONE + T::ONE) * a[p][q]);
+                let t = T::ONE / (theta.abs() + (theta * theta + T::ONE).sqrt());
+                let t = if theta < T::ZERO { -t } else { t };
+                let c = T::ONE / (t * t + T::ONE).sqrt();
+                let s = t * c;
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let squares = std::array::from_fn(|j| {
+        if a[j][j].abs() <= dimension::<T>(N) * tolerance {
+            T::ZERO
+        } else {
+            a[j][j]
+        }
+    });
+    (v, squares)
+}
@@ -0,0 +1,567 @@
+use std::marker::ConstParamTy;
+
+use crate::{
+    algebra::Signature, basis::Product, common::binomial, macros::repeat, maybe::Maybe::Just,
+    mv::MV, scalar::Scalar,
+};
+
+/// A set of grades as a bitmask, such that grade `k` is contained iff `mask & (1 << k) != 0`.
+/// Products compute the grades of their results at compile time, see [Graded].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ConstParamTy)]
+pub struct Grades(pub u64);
+
+impl Grades {
+    pub const NONE: Self = Grades(0);
+
+    pub const fn single(k: usize) -> Self {
+        Grades(1 << k)
+    }
+
+    /// All grades `0..=dim`, where the shift avoids overflowing for the 64 grades of `dim = 63`.
+    pub const fn all(dim: usize) -> Self {
+        assert!(
+            dim < u64::BITS as usize,
+            "Grades support at most 63 dimensions"
+        );
+        Grades(u64::MAX >> (u64::BITS as usize - 1 - dim))
+    }
+
+    /// The even grades up to `dim`.
+    pub const fn even(dim: usize) -> Self {
+        Grades(0x5555_5555_5555_5555 & Self::all(dim).0)
+    }
+
+    /// The odd grades up to `dim`.
+    pub const fn odd(dim: usize) -> Self {
+        Grades(0xAAAA_AAAA_AAAA_AAAA & Self::all(dim).0)
+    }
+
+    pub const fn contains(self, k: usize) -> bool {
+        self.0 & (1 << k) != 0
+    }
+
+    /// The number of basis blades with these grades in `dim` dimensions.
+    pub const fn count(self, dim: usize) -> usize {
+        let mut count = 0;
+        repeat!(k in 0..(dim + 1) {
+            if self.contains(k) {
+                count += binomial(dim, k);
+            }
+        });
+        count
+    }
+
+    /// The grades `dim - k` of the complements.
+    pub const fn complement(self, dim: usize) -> Self {
+        let mut grades = Grades::NONE;
+        repeat!(k in 0..(dim + 1) {
+            if self.contains(k) {
+                grades.0 |= 1 << (dim - k);
+            }
+        });
+        grades
+    }
+
+    /// The grades which the product of blades with these grades can have in any metric.
+    pub const fn product(self, rhs: Self, product: Product, dim: usize) -> Self {
+        let mut grades = Grades::NONE;
+        repeat!(i in 0..(dim + 1) {
+            repeat!(j in 0..(dim + 1) {
+                if self.contains(i) && rhs.contains(j) {
+                    grades.0 |= Self::pair(i, j, product, dim).0;
+                }
+            });
+        });
+        grades
+    }
+
+    pub const fn geometric(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::Geometric, dim)
+    }
+
+    pub const fn exterior(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::Exterior, dim)
+    }
+
+    pub const fn regressive(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::Regressive, dim)
+    }
+
+    pub const fn left_contraction(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::LeftContraction, dim)
+    }
+
+    pub const fn right_contraction(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::RightContraction, dim)
+    }
+
+    pub const fn inner(self, rhs: Self, dim: usize) -> Self {
+        self.product(rhs, Product::Inner, dim)
+    }
+
+    /// The grades of the product of an `i`-blade and a `j`-blade, where the anti-products
+    /// apply the rule of their product to the anti-grades.
+    const fn pair(i: usize, j: usize, product: Product, dim: usize) -> Self {
+        match product {
+            // `|i - j|, |i - j| + 2, ..., i + j` without exceeding `2 dim - i - j`,
+            // as the factors of each blade are distinct.
+            Product::Geometric | Product::Commutator | Product::AntiCommutator => {
+                let max = if i + j > dim { 2 * dim - i - j } else { i + j };
+                let mut grades = Grades::NONE;
+                let mut k = i.abs_diff(j);
+                while k <= max {
+                    grades.0 |= 1 << k;
+                    k += 2;
+                }
+                grades
+            }
+            Product::Exterior if i + j <= dim => Grades::single(i + j),
+            Product::LeftContraction if i <= j => Grades::single(j - i),
+            Product::RightContraction if i >= j => Grades::single(i - j),
+            Product::Inner => Grades::single(i.abs_diff(j)),
+            Product::HestenesInner if i != 0 && j != 0 => Grades::single(i.abs_diff(j)),
+            Product::Scalar if i == j => Grades::single(0),
+            Product::Regressive => Self::anti(i, j, Product::Exterior, dim),
+            Product::LeftAntiContraction => Self::anti(i, j, Product::LeftContraction, dim),
+            Product::RightAntiContraction => Self::anti(i, j, Product::RightContraction, dim),
+            Product::AntiGeometric => Self::anti(i, j, Product::Geometric, dim),
+            Product::AntiScalar => Self::anti(i, j, Product::Scalar, dim),
+            _ => Grades::NONE,
+        }
+    }
+
+    const fn anti(i: usize, j: usize, product: Product, dim: usize) -> Self {
+        Self::pair(dim - i, dim - j, product, dim).complement(dim)
+    }
+}
+
+/// A multivector which only stores the coefficients of the grades `G`, ordered by the index of
+/// their basis blades. Products compute the grades of their results at compile time like
+/// [crate::Blade] computes its basis, such that e.g. the exterior product of two vectors of
+/// [crate::VGA3] is a [Bivector] of three coefficients.
+pub struct Graded<A: Signature, const G: Grades, T = f64>(pub [T; G.count(A::DIM)])
+where
+    [(); G.count(A::DIM)]:;
+
+pub type ScalarPart<A, T = f64> = Graded<A, { Grades::single(0) }, T>;
+pub type Vector<A, T = f64> = Graded<A, { Grades::single(1) }, T>;
+pub type Bivector<A, T = f64> = Graded<A, { Grades::single(2) }, T>;
+pub type Trivector<A, T = f64> = Graded<A, { Grades::single(3) }, T>;
+pub type Pseudoscalar<A: Signature, T = f64> = Graded<A, { Grades::single(A::DIM) }, T>;
+pub type Even<A: Signature, T = f64> = Graded<A, { Grades::even(A::DIM) }, T>;
+pub type Odd<A: Signature, T = f64> = Graded<A, { Grades::odd(A::DIM) }, T>;
+
+impl<A: Signature, const G: Grades, T: Scalar> Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    pub const ZERO: Self = Graded([T::ZERO; G.count(A::DIM)]);
+
+    /// The indices of the basis blades of the coefficients.
+    pub fn indices() -> impl Iterator<Item = usize> {
+        (0..1 << A::DIM).filter(|i: &usize| G.contains(i.count_ones() as usize))
+    }
+
+    /// The part of `mv` with the grades `G`, dropping all others.
+    pub fn from_mv(mv: &MV<A, T>) -> Self
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+    {
+        let mut graded = Self::ZERO;
+        for (value, i) in graded.0.iter_mut().zip(Self::indices()) {
+            *value = mv.0[i];
+        }
+        graded
+    }
+
+    pub fn to_mv(&self) -> MV<A, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+    {
+        let mut mv = MV::ZERO;
+        for (&value, i) in self.0.iter().zip(Self::indices()) {
+            mv.0[i] = value;
+        }
+        mv
+    }
+
+    /// Distributes the product over the stored coefficients of both operands only, looking up
+    /// the basis blade products in the algebra's [crate::CayleyTable]. The grades `O` computed
+    /// by [Grades::product] contain the grades of all results, such that each one is stored.
+    fn typed_product<const H: Grades, const O: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+        product: Product,
+    ) -> Graded<A, O, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); O.count(A::DIM)]:,
+    {
+        let table = A::cayley(product);
+        let outputs: Vec<usize> = Graded::<A, O, T>::indices().collect();
+        let mut result = Graded::ZERO;
+        for (&lhs, i) in self.0.iter().zip(Self::indices()) {
+            if lhs == T::ZERO {
+                continue;
+            }
+            for (&rhs, j) in rhs.0.iter().zip(Graded::<A, H, T>::indices()) {
+                if rhs == T::ZERO {
+                    continue;
+                }
+                if let Just((k, sign)) = table.0[i][j] {
+                    let position = outputs
+                        .binary_search(&k)
+                        .expect("The output grades contain all products");
+                    result.0[position] = result.0[position] + sign * (lhs * rhs);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn geometric<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.geometric(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.geometric(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::Geometric)
+    }
+
+    pub fn exterior<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.exterior(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.exterior(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::Exterior)
+    }
+
+    pub fn regressive<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.regressive(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.regressive(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::Regressive)
+    }
+
+    pub fn left_contraction<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.left_contraction(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.left_contraction(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::LeftContraction)
+    }
+
+    pub fn right_contraction<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.right_contraction(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.right_contraction(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::RightContraction)
+    }
+
+    pub fn inner<const H: Grades>(
+        &self,
+        rhs: &Graded<A, H, T>,
+    ) -> Graded<A, { G.inner(H, A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+        [(); { G.inner(H, A::DIM) }.count(A::DIM)]:,
+    {
+        self.typed_product(rhs, Product::Inner)
+    }
+
+    pub fn scalar_product<const H: Grades>(&self, rhs: &Graded<A, H, T>) -> T
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); H.count(A::DIM)]:,
+    {
+        // Only the coefficients of the same basis blades contribute to the scalar.
+        let table = A::cayley(Product::Scalar);
+        let rhs_indices: Vec<usize> = Graded::<A, H, T>::indices().collect();
+        let mut result = T::ZERO;
+        for (&lhs, i) in self.0.iter().zip(Self::indices()) {
+            if let (Ok(position), Just((_, sign))) = (rhs_indices.binary_search(&i), table.0[i][i])
+            {
+                result = result + sign * (lhs * rhs.0[position]);
+            }
+        }
+        result
+    }
+
+    pub fn reverse(&self) -> Self
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        Graded::from_mv(&self.to_mv().reverse())
+    }
+
+    pub fn involute(&self) -> Self
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        Graded::from_mv(&self.to_mv().involute())
+    }
+
+    pub fn conjugate(&self) -> Self
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        Graded::from_mv(&self.to_mv().conjugate())
+    }
+
+    /// The Poincaré dual, which maps grade `k` to `DIM - k`, see [MV::dual].
+    pub fn dual(&self) -> Graded<A, { G.complement(A::DIM) }, T>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+        [(); { G.complement(A::DIM) }.count(A::DIM)]:,
+    {
+        Graded::from_mv(&self.to_mv().dual())
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> From<Graded<A, G, T>> for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); G.count(A::DIM)]:,
+{
+    fn from(graded: Graded<A, G, T>) -> Self {
+        graded.to_mv()
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> std::ops::Add for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Graded(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> std::ops::Sub for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Graded(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> std::ops::Neg for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Graded(self.0.map(|value| -value))
+    }
+}
+
+/// `A * B`: Geometric product
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::Mul<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.geometric(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.geometric(H, A::DIM) }, T>;
+
+    fn mul(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.geometric(&rhs)
+    }
+}
+
+/// `A ^ B`: Exterior product
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::BitXor<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.exterior(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.exterior(H, A::DIM) }, T>;
+
+    fn bitxor(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.exterior(&rhs)
+    }
+}
+
+/// `A & B`: Regressive product
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::BitAnd<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.regressive(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.regressive(H, A::DIM) }, T>;
+
+    fn bitand(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.regressive(&rhs)
+    }
+}
+
+/// `A | B`: Inner product
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::BitOr<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.inner(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.inner(H, A::DIM) }, T>;
+
+    fn bitor(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.inner(&rhs)
+    }
+}
+
+/// `A >> B`: Contraction of `A` onto `B`
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::Shr<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.left_contraction(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.left_contraction(H, A::DIM) }, T>;
+
+    fn shr(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.left_contraction(&rhs)
+    }
+}
+
+/// `A << B`: Contraction of `A` by `B`
+impl<A: Signature, const G: Grades, const H: Grades, T: Scalar> std::ops::Shl<Graded<A, H, T>>
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); H.count(A::DIM)]:,
+    [(); { G.right_contraction(H, A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.right_contraction(H, A::DIM) }, T>;
+
+    fn shl(self, rhs: Graded<A, H, T>) -> Self::Output {
+        self.right_contraction(&rhs)
+    }
+}
+
+/// `!A`: Poincaré dual
+impl<A: Signature, const G: Grades, T: Scalar> std::ops::Not for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+    [(); G.count(A::DIM)]:,
+    [(); { G.complement(A::DIM) }.count(A::DIM)]:,
+{
+    type Output = Graded<A, { G.complement(A::DIM) }, T>;
+
+    fn not(self) -> Self::Output {
+        self.dual()
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> std::ops::Mul<T> for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Graded(self.0.map(|value| value * rhs))
+    }
+}
+
+// Deriving these would require `A` to implement them.
+
+impl<A: Signature, const G: Grades, T: Scalar> Clone for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> Copy for Graded<A, G, T> where [(); G.count(A::DIM)]: {}
+
+impl<A: Signature, const G: Grades, T: Scalar> PartialEq for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar> std::fmt::Debug for Graded<A, G, T>
+where
+    [(); G.count(A::DIM)]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Graded").field(&G).field(&self.0).finish()
+    }
+}
+
+impl<A: Signature, const G: Grades, T: Scalar + std::fmt::Display> std::fmt::Display
+    for Graded<A, G, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); G.count(A::DIM)]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_mv())
+    }
+}
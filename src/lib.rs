@@ -1,26 +1,103 @@
+//! Without the default `nightly` feature, the crate builds on stable Rust and provides the runtime
+//! layer: the bitmask encoding of basis blades with their products, metrics, scalars and the
+//! [SparseMV] multivectors. The nightly feature adds the types sized by `generic_const_exprs`,
+//! i.e. the dense multivectors, Cayley tables, typed blades and grade-restricted types.
+
 #![allow(dead_code)]
-#![allow(incomplete_features)]
-#![feature(const_for)]
-#![feature(generic_const_exprs)]
-#![feature(min_generic_const_args)]
-#![feature(adt_const_params)]
-#![feature(inherent_associated_types)]
-#![feature(const_trait_impl)]
-#![feature(const_try)]
-#![feature(never_type)]
-#![feature(try_trait_v2)]
-#![feature(decl_macro)]
+#![allow(clippy::upper_case_acronyms)]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+// #![feature(const_for)]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+// #![feature(min_generic_const_args)]
+#![cfg_attr(feature = "nightly", feature(adt_const_params))]
+#![cfg_attr(feature = "nightly", feature(inherent_associated_types))]
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
+// #![feature(const_try)]
+#![cfg_attr(feature = "nightly", feature(never_type))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2_residual))]
 // #![feature(effects)]
 
 mod algebra;
 mod basis;
+mod bitmask;
+#[cfg(feature = "nightly")]
 mod blade;
+#[cfg(feature = "nightly")]
+mod cayley;
 mod common;
+mod error;
+mod form;
+#[cfg(feature = "nightly")]
+mod graded;
 mod macros;
 mod maybe;
 mod metric;
+#[cfg(feature = "nightly")]
 mod mv;
+mod scalar;
 mod sign;
+mod sparse;
+
+pub use crate::{
+    algebra::{
+        Algebra, CGA2, CGA3, Complex, Dual, Hyperbolic, PGA, PGA2, PGA3, Real, Signature, VGA,
+        VGA2, VGA3,
+    },
+    basis::{Basis, NonzeroBasis, Product, Unary},
+    bitmask::{BitBasis, BitMetric, NonzeroBitBasis},
+    error::Error,
+    form::BilinearForm,
+    maybe::Maybe,
+    metric::{Metric, Square},
+    scalar::{Float, Scalar},
+    sign::Sign,
+    sparse::SparseMV,
+};
+#[cfg(feature = "nightly")]
+pub use crate::{
+    algebra::CGA,
+    blade::Blade,
+    cayley::{CayleyTable, Entry},
+    graded::{Bivector, Even, Graded, Grades, Odd, Pseudoscalar, ScalarPart, Trivector, Vector},
+    mv::MV,
+};
+
+/// Glob-importable set of the items needed to work with the algebras.
+#[cfg_attr(
+    feature = "nightly",
+    doc = r#"```
+# #![allow(incomplete_features)]
+# #![feature(generic_const_exprs)]
+use generic_ga::prelude::*;
+let e0 = MV::<VGA2>::blade(1.0, NonzeroBasis::from_index(0b01));
+assert_eq!(e0.get(NonzeroBasis::from_index(0b01)), 1.0);
+```"#
+)]
+pub mod prelude {
+    pub use crate::{
+        algebra::{
+            Algebra, CGA2, CGA3, Complex, Dual, Hyperbolic, PGA, PGA2, PGA3, Real, Signature, VGA,
+            VGA2, VGA3,
+        },
+        basis::{Basis, NonzeroBasis, Product, Unary},
+        bitmask::BitBasis,
+        form::BilinearForm,
+        maybe::Maybe::{self, Just, Nothing},
+        metric::{Metric, Square},
+        scalar::{Float, Scalar},
+        sign::Sign,
+        sparse::SparseMV,
+    };
+    #[cfg(feature = "nightly")]
+    pub use crate::{
+        algebra::CGA,
+        blade::Blade,
+        cayley::CayleyTable,
+        graded::{Bivector, Even, Graded, Grades, Odd, Pseudoscalar, ScalarPart, Trivector, Vector},
+        mv::MV,
+    };
+}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "nightly"))]
 mod test;
@@ -1,10 +1,9 @@
-use std::{
-    marker::ConstParamTy,
-    ops::{ControlFlow, FromResidual, Try},
-};
+#[cfg(feature = "nightly")]
+use std::ops::{ControlFlow, FromResidual, Residual, Try};
 
 /// Reimplements `Option` but with the `ConstParamTy` trait.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ConstParamTy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
 pub enum Maybe<T> {
     Just(T),
     Nothing,
@@ -12,10 +11,11 @@ pub enum Maybe<T> {
 
 /// This compiles but is unuable because [FromResidual] and [Try]
 /// are not marked with `#[const_trait]`.
+#[cfg(feature = "nightly")]
 #[allow(dead_code)]
 impl<T> Try for Maybe<T> {
     type Output = T;
-    type Residual = ();
+    type Residual = Maybe<!>;
 
     fn from_output(output: Self::Output) -> Self {
         Maybe::Just(output)
@@ -24,14 +24,21 @@ impl<T> Try for Maybe<T> {
     fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
         match self {
             Maybe::Just(output) => ControlFlow::Continue(output),
-            Maybe::Nothing => ControlFlow::Break(()),
+            Maybe::Nothing => ControlFlow::Break(Maybe::Nothing),
         }
     }
 }
 
+#[cfg(feature = "nightly")]
 #[allow(dead_code)]
-impl<T> FromResidual<()> for Maybe<T> {
-    fn from_residual(_: ()) -> Self {
+impl<T> FromResidual<Maybe<!>> for Maybe<T> {
+    fn from_residual(_: Maybe<!>) -> Self {
         Self::Nothing
     }
 }
+
+#[cfg(feature = "nightly")]
+#[allow(dead_code)]
+impl<T> Residual<T> for Maybe<!> {
+    type TryType = Maybe<T>;
+}
@@ -1,15 +1,32 @@
-use std::marker::ConstParamTy;
+use crate::macros::repeat;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ConstParamTy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
 pub struct Metric<const N: usize>(pub [Square; N]);
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ConstParamTy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
 pub enum Square {
     Pos,
     Neg,
     Zero,
 }
 
+impl<const N: usize> Metric<N> {
+    /// The diagonal metric with `p` positive, followed by `q` negative dimensions.
+    /// All remaining dimensions are degenerate.
+    pub const fn signature(p: usize, q: usize) -> Self {
+        let mut squares = [Square::Pos; N];
+        repeat!(i in p..{p + q} {
+            squares[i] = Square::Neg;
+        });
+        repeat!(i in {p + q}..N {
+            squares[i] = Square::Zero;
+        });
+        Metric(squares)
+    }
+}
+
 impl<const N: usize> std::fmt::Display for Metric<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         for (i, square) in self.0.into_iter().enumerate() {
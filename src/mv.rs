@@ -1,76 +1,1142 @@
-// use std::fmt::Write;
-
-// use itertools::Itertools;
-
-// #[derive(Debug, Clone, Copy)]
-// pub struct MV<const N: usize>(pub [f64; basis_blade_count(N)])
-// where
-//     [f64; basis_blade_count(N)]:;
-
-// impl<const N: usize> MV<N>
-// where
-//     [f64; basis_blade_count(N)]:,
-// {
-//     pub fn add(self, rhs: MV<N>) -> MV<N> {
-//         let mut result = [0.0; basis_blade_count(N)];
-//         for i in 0..basis_blade_count(N) {
-//             result[i] = self.0[i] + rhs.0[i];
-//         }
-//         MV(result)
-//     }
-
-//     pub fn sub(self, rhs: MV<N>) -> MV<N> {
-//         let mut result = [0.0; basis_blade_count(N)];
-//         for i in 0..basis_blade_count(N) {
-//             result[i] = self.0[i] - rhs.0[i];
-//         }
-//         MV(result)
-//     }
-
-//     pub fn scale(self, rhs: f64) -> MV<N> {
-//         let mut result = [0.0; basis_blade_count(N)];
-//         for i in 0..basis_blade_count(N) {
-//             result[i] = self.0[i] * rhs;
-//         }
-//         MV(result)
-//     }
-
-//     pub fn mul(self, _rhs: f64) -> MV<N> {
-//         todo!()
-//     }
-// }
-
-// impl<const N: usize> std::fmt::Display for MV<N>
-// where
-//     [f64; basis_blade_count(N)]:,
-// {
-//     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-//         let mut factors = Vec::new();
-
-//         for i in 0..basis_blade_count(N) {
-//             let value = self.0[i];
-//             if value == 0.0 {
-//                 continue;
-//             }
-
-//             let mut suffix = String::new();
-
-//             for k in 0..N {
-//                 if i & (1 << k) != 0 {
-//                     write!(&mut suffix, "{k}")?;
-//                 }
-//             }
-
-//             factors.push((value, suffix));
-//         }
-
-//         factors.sort_by(|(_, s1), (_, s2)| s1.len().cmp(&s2.len()));
-
-//         let display = factors
-//             .into_iter()
-//             .map(|(value, suffix)| format!("{}e{}", value, suffix))
-//             .join(" + ");
-
-//         write!(f, "{display}")
-//     }
-// }
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::{
+    algebra::Signature,
+    basis::{NonzeroBasis, Product, Unary},
+    error::Error,
+    maybe::Maybe::{Just, Nothing},
+    metric::Metric,
+    scalar::{Float, Scalar},
+};
+
+/// A multivector of the algebra `A`, densely storing one coefficient per basis blade.
+/// The coefficient of the blade `eᵢⱼ..` is stored at the index with the bits `i, j, ..` set,
+/// see [NonzeroBasis::index].
+/// The coefficients are of the [Scalar] type `T`.
+pub struct MV<A: Signature, T = f64>(pub [T; A::BASIS_BLADE_COUNT])
+where
+    [(); A::BASIS_BLADE_COUNT]:;
+
+impl<A: Signature, T: Scalar> MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    pub const ZERO: Self = MV([T::ZERO; A::BASIS_BLADE_COUNT]);
+
+    pub fn scalar(value: T) -> Self {
+        let mut mv = Self::ZERO;
+        mv.0[0] = value;
+        mv
+    }
+
+    /// The blade `value * basis`, taking the sign of `basis` into account.
+    pub fn blade(value: T, basis: NonzeroBasis<{ A::DIM }>) -> Self {
+        let mut mv = Self::ZERO;
+        mv.0[basis.index()] = basis.sign * value;
+        mv
+    }
+
+    /// The coefficient of `basis`, taking the sign of `basis` into account.
+    pub fn get(&self, basis: NonzeroBasis<{ A::DIM }>) -> T {
+        basis.sign * self.0[basis.index()]
+    }
+
+    pub fn scale(self, rhs: T) -> Self {
+        MV(self.0.map(|value| value * rhs))
+    }
+
+    /// The grade `k` part `⟨X⟩ₖ`, i.e. the coefficients of the basis blades with `k` factors.
+    pub fn grade(&self, k: usize) -> Self {
+        self.filter_grades(|grade| grade == k)
+    }
+
+    /// The part of anti-grade `k`, i.e. of grade `DIM - k`.
+    pub fn anti_grade(&self, k: usize) -> Self {
+        self.filter_grades(|grade| grade + k == A::DIM)
+    }
+
+    /// The even grade part, e.g. the rotors of an algebra.
+    pub fn even(&self) -> Self {
+        self.filter_grades(|grade| grade % 2 == 0)
+    }
+
+    /// The odd grade part.
+    pub fn odd(&self) -> Self {
+        self.filter_grades(|grade| grade % 2 == 1)
+    }
+
+    /// The grades with a nonzero coefficient in ascending order.
+    pub fn grades(&self) -> Vec<usize> {
+        (0..A::BASIS_BLADE_COUNT)
+            .filter(|&i| self.0[i] != T::ZERO)
+            .map(|i| i.count_ones() as usize)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Whether all nonzero coefficients have the same grade, which holds for zero as well.
+    pub fn is_homogeneous(&self) -> bool {
+        self.grades().len() <= 1
+    }
+
+    /// The part of the basis blades without degenerate factors, which the metric can measure.
+    /// E.g. the bulk of the point `x e0 + y e1 + z e2 + w e3` of [crate::PGA3] is `(x, y, z)`.
+    pub fn bulk(&self) -> Self {
+        MV(std::array::from_fn(|i| {
+            if i as u64 & A::BIT_METRIC.zero == 0 {
+                self.0[i]
+            } else {
+                T::ZERO
+            }
+        }))
+    }
+
+    /// The part of the basis blades with degenerate factors, i.e. the complement of [MV::bulk].
+    /// E.g. the weight of the point `x e0 + y e1 + z e2 + w e3` of [crate::PGA3] is `w`.
+    pub fn weight(&self) -> Self {
+        *self - self.bulk()
+    }
+
+    /// Keeps only the coefficients of the basis blades whose grade satisfies `keep`.
+    fn filter_grades(&self, keep: impl Fn(usize) -> bool) -> Self {
+        MV(std::array::from_fn(|i| {
+            if keep(i.count_ones() as usize) {
+                self.0[i]
+            } else {
+                T::ZERO
+            }
+        }))
+    }
+
+    /// Negates the coefficients of the basis blades with one of the given `grades`.
+    fn negate_grades(&self, grades: &[usize]) -> Self {
+        MV(std::array::from_fn(|i| {
+            if grades.contains(&(i.count_ones() as usize)) {
+                -self.0[i]
+            } else {
+                self.0[i]
+            }
+        }))
+    }
+}
+
+impl<A: Signature, T: Scalar> MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    /// Distributes the basis blade `product` over the coefficients of both operands,
+    /// looking up the basis blade products in the algebra's [crate::cayley::CayleyTable].
+    pub fn product(&self, rhs: &Self, product: Product) -> Self {
+        let table = A::cayley(product);
+        let mut result = Self::ZERO;
+        for i in 0..A::BASIS_BLADE_COUNT {
+            if self.0[i] == T::ZERO {
+                continue;
+            }
+            for j in 0..A::BASIS_BLADE_COUNT {
+                if rhs.0[j] == T::ZERO {
+                    continue;
+                }
+                if let Just((k, sign)) = table.0[i][j] {
+                    result.0[k] = result.0[k] + sign * (self.0[i] * rhs.0[j]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Like [MV::product], but only computes the coefficients of the given output `grades`.
+    ///
+    /// The basis blade products have the factors `i ^ j` which are not shared, or their
+    /// complement for the anti-products, such that the only `j` which can contribute to the
+    /// output `k` are `i ^ k` and `i ^ !k`. Thus the pairs of coefficients which do not
+    /// contribute to the kept grades are never visited.
+    pub fn grade_product(&self, rhs: &Self, product: Product, grades: &[usize]) -> Self {
+        let table = A::cayley(product);
+        let pseudoscalar = A::BASIS_BLADE_COUNT - 1;
+        let outputs: Vec<usize> = (0..A::BASIS_BLADE_COUNT)
+            .filter(|k| grades.contains(&(k.count_ones() as usize)))
+            .collect();
+        let mut result = Self::ZERO;
+        for i in 0..A::BASIS_BLADE_COUNT {
+            if self.0[i] == T::ZERO {
+                continue;
+            }
+            for &k in &outputs {
+                let complement = !k & pseudoscalar;
+                let complement = (complement != k).then_some(i ^ complement);
+                for j in std::iter::once(i ^ k).chain(complement) {
+                    if rhs.0[j] == T::ZERO {
+                        continue;
+                    }
+                    if let Just((index, sign)) = table.0[i][j]
+                        && index == k
+                    {
+                        result.0[k] = result.0[k] + sign * (self.0[i] * rhs.0[j]);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Maps each basis blade using the `unary` operator, keeping its coefficient.
+    pub fn unary(&self, unary: Unary) -> Self {
+        let metric = A::metric();
+        let mut result = Self::ZERO;
+        for i in 0..A::BASIS_BLADE_COUNT {
+            match NonzeroBasis::<{ A::DIM }>::from_index(i).unary(unary, metric) {
+                Just(basis) => result.0[basis.index()] = basis.sign * self.0[i],
+                Nothing => {}
+            }
+        }
+        result
+    }
+
+    pub fn geometric(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Geometric)
+    }
+
+    pub fn exterior(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Exterior)
+    }
+
+    pub fn regressive(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Regressive)
+    }
+
+    pub fn left_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::LeftContraction)
+    }
+
+    pub fn right_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::RightContraction)
+    }
+
+    /// Dorst's scalar-preserving inner product, see [NonzeroBasis::inner].
+    pub fn inner(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Inner)
+    }
+
+    /// Hestenes' inner product, see [NonzeroBasis::hestenes_inner].
+    pub fn hestenes_inner(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::HestenesInner)
+    }
+
+    /// See [NonzeroBasis::left_anti_contraction].
+    pub fn left_anti_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::LeftAntiContraction)
+    }
+
+    /// See [NonzeroBasis::right_anti_contraction].
+    pub fn right_anti_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::RightAntiContraction)
+    }
+
+    /// Scalar product, see [NonzeroBasis::scalar].
+    pub fn scalar_product(&self, rhs: &Self) -> T {
+        self.product(rhs, Product::Scalar).0[0]
+    }
+
+    /// Geometric antiproduct, see [NonzeroBasis::anti_geometric].
+    pub fn anti_geometric(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::AntiGeometric)
+    }
+
+    /// Anti-scalar product as the coefficient of `I`, see [NonzeroBasis::anti_scalar].
+    pub fn anti_scalar_product(&self, rhs: &Self) -> T {
+        self.product(rhs, Product::AntiScalar).0[A::BASIS_BLADE_COUNT - 1]
+    }
+
+    /// Commutator product `(AB - BA) / 2`, e.g. the Lie bracket of bivectors,
+    /// see [NonzeroBasis::commutator].
+    pub fn commutator(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Commutator)
+    }
+
+    /// Anti-commutator product `(AB + BA) / 2`, see [NonzeroBasis::anti_commutator].
+    pub fn anti_commutator(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::AntiCommutator)
+    }
+
+    /// The squared norm `⟨A Ã⟩₀`, which is negative for e.g. `e4` of [crate::CGA3] or `e0` of
+    /// [crate::Complex], and zero for null vectors.
+    pub fn norm_squared(&self) -> T {
+        self.scalar_product(&self.reverse())
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.unary(Unary::Reverse)
+    }
+
+    pub fn involute(&self) -> Self {
+        self.unary(Unary::Involute)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        self.unary(Unary::Conjugate)
+    }
+
+    /// See [NonzeroBasis::anti_reverse].
+    pub fn anti_reverse(&self) -> Self {
+        self.unary(Unary::AntiReverse)
+    }
+
+    /// See [NonzeroBasis::right_complement].
+    pub fn right_complement(&self) -> Self {
+        self.unary(Unary::RightComplement)
+    }
+
+    /// See [NonzeroBasis::left_complement].
+    pub fn left_complement(&self) -> Self {
+        self.unary(Unary::LeftComplement)
+    }
+
+    /// See [NonzeroBasis::hodge].
+    pub fn hodge(&self) -> Self {
+        self.unary(Unary::Hodge)
+    }
+
+    /// See [NonzeroBasis::dual].
+    pub fn dual(&self) -> Self {
+        self.unary(Unary::Dual)
+    }
+}
+
+impl<A: Signature, T: Float> MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    /// The inverse `A⁻¹` with `A A⁻¹ = A⁻¹ A = 1`.
+    /// Uses the closed forms of Hitzer and Sangwine up to dimension 5, which only take a few
+    /// geometric products, and [MV::inverse_by_elimination] in higher dimensions.
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let conjugate = self.conjugate();
+        let numerator = match A::DIM {
+            0..=2 => conjugate,
+            3 => conjugate * self.involute() * self.reverse(),
+            4 => {
+                let product = *self * conjugate;
+                conjugate * product.negate_grades(&[3, 4])
+            }
+            5 => {
+                let numerator = conjugate * self.involute() * self.reverse();
+                let product = *self * numerator;
+                numerator * product.negate_grades(&[1, 4])
+            }
+            _ => return self.inverse_by_elimination(),
+        };
+        // The closed forms guarantee that this product is a scalar, which is bounded by the
+        // norms of its factors, such that the tolerance does not depend on the magnitude.
+        let denominator = self.geometric(&numerator).0[0];
+        if denominator.abs() <= T::EPSILON * self.l1_norm() * numerator.l1_norm() {
+            return Err(Error::NotInvertible);
+        }
+        Ok(numerator.scale(T::ONE / denominator))
+    }
+
+    /// The inverse `Ã / (A Ã)` of a versor, i.e. a geometric product of invertible vectors,
+    /// for which `A Ã` is a scalar.
+    /// This is cheaper than [MV::inverse], but is not the inverse of other multivectors.
+    pub fn versor_inverse(&self) -> Result<Self, Error> {
+        let norm_squared = self.norm_squared();
+        if norm_squared.abs() <= T::EPSILON * self.l1_norm() * self.l1_norm() {
+            return Err(Error::NotInvertible);
+        }
+        Ok(self.reverse().scale(T::ONE / norm_squared))
+    }
+
+    /// The sandwich product `V X V⁻¹` of the versor `V` with `X`.
+    /// As versors preserve grades, the product with `V⁻¹` only computes the grades of `X`,
+    /// see [MV::grade_product].
+    /// Fails if `V` is not invertible.
+    pub fn sandwich(&self, x: &Self) -> Result<Self, Error> {
+        let inverse = self.versor_inverse()?;
+        let product = self.geometric(x);
+        Ok(product.grade_product(&inverse, Product::Geometric, &x.grades()))
+    }
+
+    /// Applies the versor `V` to `X`, i.e. the [MV::sandwich] `V X V⁻¹` for even versors
+    /// and `V X̂ V⁻¹` with the grade involution of `X` for odd versors,
+    /// such that e.g. a vector `V` reflects `X` in the hyperplane orthogonal to `V`.
+    /// Fails with [Error::NotVersor] if `V` has significant even and odd parts.
+    pub fn transform(&self, x: &Self) -> Result<Self, Error> {
+        // Products of vectors leave rounding errors in the vanishing part.
+        let (even, odd) = (self.even().l1_norm(), self.odd().l1_norm());
+        if even <= T::EPSILON.sqrt() * odd {
+            self.sandwich(&x.involute())
+        } else if odd <= T::EPSILON.sqrt() * even {
+            self.sandwich(x)
+        } else {
+            Err(Error::NotVersor)
+        }
+    }
+
+    /// The norm `√|⟨A Ã⟩₀|`, which uses the absolute value of a negative [MV::norm_squared].
+    pub fn norm(&self) -> T {
+        self.norm_squared().abs().sqrt()
+    }
+
+    /// Divides by the [MV::norm], such that the squared norm becomes `1` or `-1`.
+    /// Fails for a zero norm, e.g. for null vectors.
+    pub fn normalized(&self) -> Result<Self, Error> {
+        // Relative to the coefficients, such that small but valid elements can be normalized,
+        // while the rounding errors of null elements like `e3 + e4` of CGA3 are zero.
+        let norm = self.norm();
+        if norm * norm <= T::EPSILON * self.l1_norm() * self.l1_norm() {
+            return Err(Error::ZeroNorm);
+        }
+        Ok(self.scale(T::ONE / norm))
+    }
+
+    /// The norm of the [MV::bulk], which equals the [MV::norm] as the metric cannot measure
+    /// the weight.
+    pub fn bulk_norm(&self) -> T {
+        self.bulk().norm()
+    }
+
+    /// The norm of the [MV::weight], measured as the bulk norm of its Poincaré dual.
+    /// E.g. the weight norm of the point `x e0 + y e1 + z e2 + w e3` of [crate::PGA3] is `|w|`.
+    /// It is zero in non-degenerate algebras.
+    pub fn weight_norm(&self) -> T {
+        self.weight().dual().norm()
+    }
+
+    /// Divides by the [MV::weight_norm], e.g. to scale a point of [crate::PGA3] to `w = ±1`.
+    /// Fails for a zero weight, e.g. for points at infinity.
+    pub fn unitize(&self) -> Result<Self, Error> {
+        // Relative to the weight instead of the whole element, such that points far away
+        // from the origin can be unitized as well.
+        let weight_norm = self.weight_norm();
+        let scale = self.weight().l1_norm();
+        if weight_norm * weight_norm <= T::EPSILON * scale * scale {
+            return Err(Error::ZeroNorm);
+        }
+        Ok(self.scale(T::ONE / weight_norm))
+    }
+
+    /// The inverse computed by solving `A X = 1` for `X` with Gaussian elimination,
+    /// where the left multiplication by `A` is a matrix acting on the coefficients.
+    /// Works in any dimension, but takes `O(BASIS_BLADE_COUNT³)` operations.
+    pub fn inverse_by_elimination(&self) -> Result<Self, Error> {
+        let n = A::BASIS_BLADE_COUNT;
+        let table = A::cayley(Product::Geometric);
+        // The augmented matrix `[L | 1]`, where column `j` of `L` is `A eⱼ`.
+        let mut matrix = vec![vec![T::ZERO; n + 1]; n];
+        for (i, entries) in table.0.iter().enumerate() {
+            for (j, entry) in entries.iter().enumerate() {
+                if let Just((k, sign)) = *entry {
+                    matrix[k][j] = matrix[k][j] + sign * self.0[i];
+                }
+            }
+        }
+        matrix[0][n] = T::ONE;
+
+        let mut max = T::ZERO;
+        for value in matrix.iter().flatten() {
+            if value.abs() > max {
+                max = value.abs();
+            }
+        }
+        let tolerance = max * T::EPSILON;
+
+        for column in 0..n {
+            let mut pivot = column;
+            for row in column + 1..n {
+                if matrix[row][column].abs() > matrix[pivot][column].abs() {
+                    pivot = row;
+                }
+            }
+            if matrix[pivot][column].abs() <= tolerance {
+                return Err(Error::NotInvertible);
+            }
+            matrix.swap(column, pivot);
+
+            let pivot = matrix[column].clone();
+            for (i, row) in matrix.iter_mut().enumerate() {
+                if i == column || row[column] == T::ZERO {
+                    continue;
+                }
+                let factor = row[column] / pivot[column];
+                for (value, pivot) in row[column..].iter_mut().zip(&pivot[column..]) {
+                    *value = *value - factor * *pivot;
+                }
+            }
+        }
+
+        Ok(MV(std::array::from_fn(|i| matrix[i][n] / matrix[i][i])))
+    }
+
+    /// Orthogonal projection `(X >> B) >> B⁻¹` of `X` onto the blade `B`.
+    /// Fails if `B` is not invertible, e.g. for null blades of degenerate metrics.
+    pub fn project(&self, blade: &Self) -> Result<Self, Error> {
+        let inverse = blade.versor_inverse()?;
+        Ok(self.left_contraction(blade).left_contraction(&inverse))
+    }
+
+    /// Rejection `(X ^ B) << B⁻¹` of `X` from the blade `B`.
+    /// For vectors, this is the part orthogonal to `B`, i.e. `X - X.project(B)`.
+    /// Fails if `B` is not invertible, e.g. for null blades of degenerate metrics.
+    pub fn reject(&self, blade: &Self) -> Result<Self, Error> {
+        let inverse = blade.versor_inverse()?;
+        Ok(self.exterior(blade).right_contraction(&inverse))
+    }
+
+    /// Join `A ∪ B` of the blades `A` and `B`, i.e. the blade of the smallest subspace
+    /// containing both, up to scale. Equals `A ∧ B` if the subspaces are disjoint.
+    /// Otherwise, the factors of `B` (see [MV::factorize]) not contained in `A` are added one by one,
+    /// such that the result does not depend on the metric and works in degenerate algebras as well.
+    pub fn join(&self, rhs: &Self) -> Self {
+        let (lhs_norm, rhs_norm) = (self.euclidean_norm(), rhs.euclidean_norm());
+        if lhs_norm == T::ZERO || rhs_norm == T::ZERO {
+            return Self::ZERO;
+        }
+        let mut join = self.scale(T::ONE / lhs_norm);
+        if !(join ^ rhs.scale(T::ONE / rhs_norm)).is_negligible(T::ONE) {
+            return *self ^ *rhs;
+        }
+        for factor in rhs.factorize() {
+            let extended = join ^ factor.scale(T::ONE / factor.euclidean_norm());
+            if !extended.is_negligible(T::ONE) {
+                join = extended;
+            }
+        }
+        join
+    }
+
+    /// Meet `A ∩ B` of the blades `A` and `B`, i.e. the blade of the intersection of their
+    /// subspaces, up to scale. Computed as `(B ⌋ J⁻¹) ⌋ A` relative to the [MV::join] `J`,
+    /// which unlike the [MV::regressive] product does not require `A` and `B` to span the space.
+    pub fn meet(&self, rhs: &Self) -> Self {
+        let join = self.join(rhs);
+        if join == Self::ZERO {
+            return Self::ZERO;
+        }
+        let inverse = join.euclidean_inverse();
+        rhs.euclidean_contraction(&inverse)
+            .euclidean_contraction(self)
+    }
+
+    /// Factorizes a k-blade `X` into `k` vectors with `f₁ ∧ ... ∧ fₖ = X`, which span its subspace.
+    /// The factors are orthogonal with respect to the Euclidean metric of the coefficients,
+    /// which also exists for null blades of degenerate or conformal metrics.
+    /// All but the last one are unit vectors, the last one carries the magnitude of `X`.
+    /// Scalars have no factors, and the result is meaningless if `X` is not a blade.
+    ///
+    /// Each factor is the projection of a basis vector of the largest coefficient of `X`
+    /// onto the remaining blade, which is then reduced by contracting it with that factor.
+    pub fn factorize(&self) -> Vec<Self> {
+        let grades = self.grades();
+        let [k] = grades[..] else {
+            return Vec::new();
+        };
+        if k == 0 {
+            return Vec::new();
+        }
+        let largest = (0..A::BASIS_BLADE_COUNT)
+            .max_by(|&i, &j| self.0[i].abs().total_cmp(&self.0[j].abs()))
+            .unwrap();
+        let mut rest = *self;
+        let mut factors = Vec::with_capacity(k);
+        for i in (0..A::DIM).filter(|i| largest & 1 << i != 0).take(k - 1) {
+            let vector = MV::blade(T::ONE, NonzeroBasis::from_index(1 << i));
+            let factor = vector
+                .euclidean_contraction(&rest)
+                .euclidean_contraction(&rest.euclidean_inverse());
+            let factor = factor.scale(T::ONE / factor.euclidean_norm());
+            rest = factor.euclidean_contraction(&rest);
+            factors.push(factor);
+        }
+        factors.push(rest);
+        factors
+    }
+
+    /// Whether this is a blade, i.e. the exterior product of vectors.
+    /// A homogeneous k-vector `X` is a blade iff `(E ⌋ X) ∧ X = 0` for all basis (k-1)-blades `E`
+    /// (the Plücker relations), where the contraction uses a Euclidean metric,
+    /// such that the test works in degenerate algebras as well.
+    pub fn is_blade(&self) -> bool {
+        let grades = self.grades();
+        let [k] = grades[..] else {
+            return grades.is_empty();
+        };
+        if k <= 1 || k + 1 >= A::DIM {
+            return true;
+        }
+
+        // The relations are quadratic in `X`, so they are checked for `X / |X|₁`.
+        let unit = self.scale(T::ONE / self.l1_norm());
+        for j in (0..A::BASIS_BLADE_COUNT).filter(|j| j.count_ones() as usize == k - 1) {
+            let factor = MV::blade(T::ONE, NonzeroBasis::from_index(j));
+            let contraction = factor.euclidean_contraction(&unit);
+            if !(contraction ^ unit).is_negligible(T::ONE) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether this is a versor, i.e. the geometric product of invertible vectors.
+    /// Checks that `X` has a single parity, `X X̃` is a nonzero scalar and `X̂ v X⁻¹` maps
+    /// every vector `v` to a vector.
+    pub fn is_versor(&self) -> bool {
+        let norm = self.l1_norm();
+        if norm == T::ZERO {
+            return false;
+        }
+        let unit = self.scale(T::ONE / norm);
+        if !unit.even().is_negligible(T::ONE) && !unit.odd().is_negligible(T::ONE) {
+            return false;
+        }
+        let Ok(inverse) = unit.versor_inverse() else {
+            return false;
+        };
+        if !(unit * inverse - MV::scalar(T::ONE)).is_negligible(T::ONE) {
+            return false;
+        }
+        let involute = unit.involute();
+        (0..A::DIM).all(|i| {
+            let vector = MV::blade(T::ONE, NonzeroBasis::from_index(1 << i));
+            let image = involute * vector * inverse;
+            (image - image.grade(1)).is_negligible(T::ONE)
+        })
+    }
+
+    /// Decomposes a versor `V` into unit vectors `n₁ ... nₘ`, whose product is `V` up to a scalar
+    /// factor, i.e. the transformation of `V` is the composition of the reflections in the
+    /// hyperplanes orthogonal to the `nᵢ` (Cartan–Dieudonné). Scalars have no reflections.
+    ///
+    /// Each basis vector `x` is mapped back onto itself by reflecting its image `y` in `y - x`,
+    /// or in `y + x` followed by `x` if `y - x` is null. As the earlier basis vectors are fixed
+    /// and orthogonal to `x` and `y`, at most `2 DIM` reflections are needed.
+    /// Fails for degenerate algebras, whose null vectors have no reflections, and for non-versors.
+    pub fn reflections(&self) -> Result<Vec<Self>, Error> {
+        if A::R > 0 {
+            return Err(Error::DegenerateMetric);
+        }
+        if !self.is_versor() {
+            return Err(Error::NotVersor);
+        }
+        let mut rest = *self;
+        let mut reflections = Vec::new();
+        for i in 0..A::DIM {
+            let x = MV::blade(T::ONE, NonzeroBasis::from_index(1 << i));
+            let y = rest.transform(&x)?;
+            if (y - x).is_negligible(T::ONE) {
+                continue;
+            }
+            // `(y - x)² + (y + x)² = 4 x²`, such that one of them is far from null.
+            let normals = if (y - x).norm_squared().abs() >= T::ONE {
+                vec![y - x]
+            } else {
+                vec![y + x, x]
+            };
+            for normal in normals {
+                let normal = normal.normalized()?;
+                rest = normal * rest;
+                reflections.push(normal);
+            }
+        }
+        Ok(reflections)
+    }
+
+    /// The exponential `e^X = 1 + X + X²/2! + ..`, e.g. the rotor, motor or conformal
+    /// transformation generated by the bivector `X`.
+    ///
+    /// The scalar part of `X` commutes with everything and just scales the result.
+    /// If the rest squares to a scalar `λ`, e.g. a simple bivector, the closed form
+    /// `cos √-λ + X sin √-λ / √-λ`, `cosh √λ + X sinh √λ / √λ` or `1 + X` is used,
+    /// depending on whether `λ` is negative, positive or zero.
+    /// Bivectors of up to 5 dimensions are split into at most two commuting simple bivectors,
+    /// see [MV::split_bivector]. Everything else falls back to [MV::exp_by_series].
+    pub fn exp(&self) -> Self {
+        let scalar = self.0[0];
+        let x = *self - MV::scalar(scalar);
+        let square = x * x;
+
+        let exp = if x.squares_to_scalar(&square) {
+            let (cos, sinc) = Self::cos_sinc(square.0[0]);
+            MV::scalar(cos) + x.scale(sinc)
+        } else {
+            match x.split_bivector() {
+                Some(Split::Distinct(x1, lambda1, x2, lambda2)) => {
+                    let (cos1, sinc1) = Self::cos_sinc(lambda1);
+                    let (cos2, sinc2) = Self::cos_sinc(lambda2);
+                    (MV::scalar(cos1) + x1.scale(sinc1)) * (MV::scalar(cos2) + x2.scale(sinc2))
+                }
+                // `(c + k B₁)(c + k B₂) = c² + c k B + k² W / 2`
+                Some(Split::Isoclinic(lambda, w)) => {
+                    let (cos, sinc) = Self::cos_sinc(lambda);
+                    let half = T::ONE / (T::ONE + T::ONE);
+                    MV::scalar(cos * cos) + x.scale(cos * sinc) + w.scale(sinc * sinc * half)
+                }
+                None => x.exp_by_series(),
+            }
+        };
+        exp.scale(scalar.exp())
+    }
+
+    /// The exponential computed by its Taylor series, after dividing `X` by a power of two
+    /// such that the series converges quickly, and squaring the result as often.
+    pub fn exp_by_series(&self) -> Self {
+        let two = T::ONE + T::ONE;
+        let mut x = *self;
+        let mut squarings = 0;
+        while x.l1_norm() > T::ONE / two {
+            x = x.scale(T::ONE / two);
+            squarings += 1;
+        }
+
+        let mut result = MV::scalar(T::ONE);
+        let mut term = MV::scalar(T::ONE);
+        let mut k = T::ONE;
+        while term.l1_norm() > T::EPSILON {
+            term = (term * x).scale(T::ONE / k);
+            result = result + term;
+            k = k + T::ONE;
+        }
+
+        for _ in 0..squarings {
+            result = result * result;
+        }
+        result
+    }
+
+    /// The logarithm of a rotor, motor or other exponential of a bivector `X`,
+    /// such that `X.exp().log() == X` if all rotation angles of `X` are small enough.
+    /// Inverts the closed forms of [MV::exp] by recovering the angles from the scalar,
+    /// bivector and 4-vector parts.
+    ///
+    /// Fails for elements which are not exponentials, like rotors of negative norm,
+    /// or whose logarithm is ambiguous, like rotations by `π`.
+    pub fn log(&self) -> Result<Self, Error> {
+        let c = self.0[0];
+        let x = *self - MV::scalar(c);
+        let square = x * x;
+
+        if x.squares_to_scalar(&square) {
+            // `(c + X)(c - X) = c² - X²` is the squared norm.
+            let lambda = square.0[0];
+            let norm = Self::positive_sqrt(c * c - lambda, c * c + lambda.abs())?;
+            let log = Self::log_simple(c / norm, x.scale(T::ONE / norm), lambda / (norm * norm))?;
+            return Ok(log + MV::scalar(norm.ln()));
+        }
+
+        let bivector = x.grade(2);
+        if (x - bivector - x.grade(4)).l1_norm() > T::EPSILON * x.l1_norm() {
+            return Err(Error::NoLogarithm);
+        }
+        let norm = Self::positive_sqrt(self.norm_squared(), self.l1_norm() * self.l1_norm())?;
+        let (c, bivector) = (c / norm, bivector.scale(T::ONE / norm));
+
+        // `R = (c₁ + X₁)(c₂ + X₂) = c₁c₂ + c₂X₁ + c₁X₂ + X₁X₂`, where each factor has unit norm,
+        // i.e. `c₁² - X₁² = 1`. The bivector part splits into `c₂X₁` and `c₁X₂`, and
+        // `c² - (c₂X₁)² = c₁²c₂² - c₂²(c₁² - 1) = c₂²`.
+        let log = match bivector.split_bivector().ok_or(Error::NoLogarithm)? {
+            Split::Distinct(x1, lambda1, x2, lambda2) => {
+                let c2 = Self::positive_sqrt(c * c - lambda1, c * c + lambda1.abs())?;
+                let c1 = c / c2;
+                if c1.abs() <= T::EPSILON {
+                    return Err(Error::NoLogarithm);
+                }
+                let log1 = Self::log_simple(c1, x1.scale(T::ONE / c2), lambda1 / (c2 * c2))?;
+                let log2 = Self::log_simple(c2, x2.scale(T::ONE / c1), lambda2 / (c1 * c1))?;
+                log1 + log2
+            }
+            // Both factors share `c₁ = c₂ = √c` and `X₁² = X₂² = c₁² - 1`.
+            Split::Isoclinic(..) => {
+                let c1 = Self::positive_sqrt(c, T::ONE)?;
+                Self::log_simple(c1, bivector.scale(T::ONE / c1), c1 * c1 - T::ONE)?
+            }
+        };
+        Ok(log + MV::scalar(norm.ln()))
+    }
+
+    /// Interpolates between `self` at `t = 0` and `target` at `t = 1` along the shortest path,
+    /// i.e. `self (self⁻¹ target)^t` for versors like rotors and motors.
+    pub fn interpolate(&self, target: &Self, t: T) -> Result<Self, Error> {
+        let difference = self.versor_inverse()? * *target;
+        Ok(*self * difference.log()?.scale(t).exp())
+    }
+
+    /// Decomposes a rotor or motor `M` into commuting simple factors `e^B₁ e^B₂ = M`, whose
+    /// bivectors square to scalars, i.e. each one is a rotation, translation or boost.
+    /// E.g. a screw motion of [crate::PGA3] splits into a rotation around and a translation
+    /// along its axis. Simple elements are returned as their own single factor.
+    ///
+    /// The [MV::log] is split with the invariant decomposition, or for isoclinic bivectors
+    /// by the invariant plane `a ∧ (a ⌋ B)` of a basis vector `a`.
+    /// Fails for elements without logarithm and for more than 5 dimensions.
+    pub fn simple_motors(&self) -> Result<Vec<Self>, Error> {
+        let log = self.log()?;
+        let (scalar, bivector) = (log.grade(0), log - log.grade(0));
+        let square = bivector * bivector;
+        if bivector.squares_to_scalar(&square) {
+            return Ok(vec![log.exp()]);
+        }
+        let (b1, b2) = match bivector.split_bivector() {
+            Some(Split::Distinct(b1, _, b2, _)) => (b1, b2),
+            Some(Split::Isoclinic(lambda, _)) => {
+                let plane = (0..A::DIM)
+                    .map(|i| {
+                        let a = MV::blade(T::ONE, NonzeroBasis::from_index(1 << i));
+                        a ^ (a >> bivector)
+                    })
+                    .max_by(|p, q| p.l1_norm().total_cmp(&q.l1_norm()))
+                    .unwrap();
+                let ratio = lambda / (plane * plane).0[0];
+                if ratio.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Greater) {
+                    return Err(Error::NoLogarithm);
+                }
+                let b1 = plane.scale(ratio.sqrt());
+                (b1, bivector - b1)
+            }
+            None => return Err(Error::NoLogarithm),
+        };
+        Ok(vec![(scalar + b1).exp(), b2.exp()])
+    }
+
+    /// Splits a bivector `B` of up to 5 dimensions into the commuting simple bivectors
+    /// `B₁ + B₂ = B` with the scalar squares `λ₁` and `λ₂`.
+    ///
+    /// As `B² = λ₁ + λ₂ + 2 B₁ B₂` has a scalar and a 4-vector part `W = 2 B₁ B₂`,
+    /// the squares are the roots `λ = (⟨B²⟩₀ ± √(⟨B²⟩₀² - W²)) / 2`
+    /// and `B₁ = (λ₁ B - ½ W B) / (λ₁ - λ₂)`.
+    /// Returns [None] for other elements and if the roots are complex.
+    fn split_bivector(&self) -> Option<Split<Self, T>> {
+        if A::DIM > 5 || (*self - self.grade(2)).l1_norm() > T::EPSILON {
+            return None;
+        }
+        let two = T::ONE + T::ONE;
+        let square = *self * *self;
+        let s = square.0[0];
+        let w = square.grade(4);
+        let discriminant = s * s - w.scalar_product(&w);
+        let tolerance = T::EPSILON.sqrt() * s.abs();
+        if discriminant.abs() <= tolerance * tolerance {
+            return Some(Split::Isoclinic(s / two, w));
+        } else if discriminant < T::ZERO {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let (lambda1, lambda2) = ((s + root) / two, (s - root) / two);
+        let x1 = (self.scale(lambda1) - (w * *self).scale(T::ONE / two)).scale(T::ONE / root);
+        Some(Split::Distinct(x1, lambda1, *self - x1, lambda2))
+    }
+
+    /// The scalar factors of the exponential `cos + sinc X` of an element with the scalar square
+    /// `X² = lambda`, i.e. `cos √-λ` and `sin √-λ / √-λ` if it is negative, their hyperbolic
+    /// counterparts if it is positive, and `1` if it is zero.
+    fn cos_sinc(lambda: T) -> (T, T) {
+        if lambda.abs() <= T::EPSILON {
+            (T::ONE, T::ONE)
+        } else if lambda < T::ZERO {
+            let angle = (-lambda).sqrt();
+            (angle.cos(), angle.sin() / angle)
+        } else {
+            let angle = lambda.sqrt();
+            (angle.cosh(), angle.sinh() / angle)
+        }
+    }
+
+    /// The logarithm of the unit element `c + X` with the scalar square `X² = lambda`.
+    fn log_simple(c: T, x: Self, lambda: T) -> Result<Self, Error> {
+        if lambda.abs() <= T::EPSILON {
+            if c <= T::ZERO {
+                return Err(Error::NoLogarithm);
+            }
+            Ok(x.scale(T::ONE / c))
+        } else if lambda < T::ZERO {
+            let sin = (-lambda).sqrt();
+            Ok(x.scale(sin.atan2(c) / sin))
+        } else {
+            let sinh = lambda.sqrt();
+            if c <= T::ZERO {
+                return Err(Error::NoLogarithm);
+            }
+            Ok(x.scale((c + sinh).ln() / sinh))
+        }
+    }
+
+    /// The square root of `value`, which must be positive to take a logarithm, beyond the
+    /// rounding errors of the terms of magnitude `scale` it is computed from.
+    fn positive_sqrt(value: T, scale: T) -> Result<T, Error> {
+        if value <= T::EPSILON * scale {
+            return Err(Error::NoLogarithm);
+        }
+        Ok(value.sqrt())
+    }
+
+    /// Whether all coefficients vanish up to the rounding errors of values of magnitude `scale`.
+    fn is_negligible(&self, scale: T) -> bool {
+        self.l1_norm() <= T::EPSILON.sqrt() * (T::ONE + scale)
+    }
+
+    /// Left contraction `A ⌋ B` as if all basis vectors squared to `1`, which only depends on the
+    /// subspaces of blades, such that it can be used for metric-free constructions.
+    fn euclidean_contraction(&self, rhs: &Self) -> Self {
+        let euclidean = Metric::<{ A::DIM }>::signature(A::DIM, 0);
+        let mut result = Self::ZERO;
+        for i in (0..A::BASIS_BLADE_COUNT).filter(|&i| self.0[i] != T::ZERO) {
+            for j in (0..A::BASIS_BLADE_COUNT).filter(|&j| rhs.0[j] != T::ZERO) {
+                let lhs = NonzeroBasis::<{ A::DIM }>::from_index(i);
+                if let Just(basis) = lhs.left_contraction(NonzeroBasis::from_index(j), euclidean) {
+                    let index = basis.index();
+                    result.0[index] = result.0[index] + basis.sign * (self.0[i] * rhs.0[j]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Euclidean norm `√(Σ xᵢ²)` of the coefficients.
+    fn euclidean_norm(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::ZERO, |norm, &value| norm + value * value)
+            .sqrt()
+    }
+
+    /// Inverse `X̃ / |X|²` of a blade in the Euclidean metric of [MV::euclidean_contraction].
+    fn euclidean_inverse(&self) -> Self {
+        let norm = self.euclidean_norm();
+        self.reverse().scale(T::ONE / (norm * norm))
+    }
+
+    /// Whether the `square` `X²` is a scalar up to rounding errors, which grow like `|X|₁²`
+    /// in the cancelling terms, e.g. of the 4-vector part of a simple bivector.
+    fn squares_to_scalar(&self, square: &Self) -> bool {
+        let norm = self.l1_norm();
+        (*square - MV::scalar(square.0[0])).l1_norm() <= T::EPSILON * norm * norm
+    }
+
+    /// Sum of the absolute values of the coefficients, which bounds the coefficients of products
+    /// as `|A B|₁ <= |A|₁ |B|₁`.
+    fn l1_norm(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::ZERO, |norm, &value| norm + value.abs())
+    }
+}
+
+/// The invariant decomposition of a bivector, see [MV::split_bivector].
+enum Split<M, T> {
+    /// The simple bivectors `B₁` and `B₂` with the distinct squares `λ₁` and `λ₂`.
+    Distinct(M, T, M, T),
+    /// Both simple bivectors have the same square `λ`, such that the split is not unique,
+    /// but `W = 2 B₁ B₂` is.
+    Isoclinic(T, M),
+}
+
+impl<A: Signature, T: Scalar> std::ops::Add for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        MV(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Sub for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        MV(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Neg for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        MV(self.0.map(|value| -value))
+    }
+}
+
+/// `A * B`: Geometric product
+impl<A: Signature, T: Scalar> std::ops::Mul for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.geometric(&rhs)
+    }
+}
+
+/// `A ^ B`: Exterior product
+impl<A: Signature, T: Scalar> std::ops::BitXor for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.exterior(&rhs)
+    }
+}
+
+/// `A & B`: Regressive product
+impl<A: Signature, T: Scalar> std::ops::BitAnd for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.regressive(&rhs)
+    }
+}
+
+/// `A | B`: Inner product
+impl<A: Signature, T: Scalar> std::ops::BitOr for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.inner(&rhs)
+    }
+}
+
+/// `A >> B`: Contraction of `A` onto `B`
+impl<A: Signature, T: Scalar> std::ops::Shr for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn shr(self, rhs: Self) -> Self {
+        self.left_contraction(&rhs)
+    }
+}
+
+/// `A << B`: Contraction of `A` by `B`
+impl<A: Signature, T: Scalar> std::ops::Shl for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self {
+        self.right_contraction(&rhs)
+    }
+}
+
+/// `!A`: Poincaré dual
+impl<A: Signature, T: Scalar> std::ops::Not for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+    [(); A::DIM]:,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.dual()
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Mul<T> for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.scale(rhs)
+    }
+}
+
+// Deriving these would require `A` to implement them.
+
+impl<A: Signature, T: Scalar> Clone for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Signature, T: Scalar> Copy for MV<A, T> where [(); A::BASIS_BLADE_COUNT]: {}
+
+impl<A: Signature, T: Scalar> PartialEq for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A: Signature, T: Scalar> std::fmt::Debug for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("MV").field(&self.0).finish()
+    }
+}
+
+impl<A: Signature, T: Scalar + std::fmt::Display> std::fmt::Display for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut factors = Vec::new();
+
+        for i in 0..A::BASIS_BLADE_COUNT {
+            let value = self.0[i];
+            if value == T::ZERO {
+                continue;
+            }
+
+            let mut suffix = String::new();
+            if i != 0 {
+                write!(&mut suffix, "e")?;
+            }
+
+            for k in 0..A::DIM {
+                if i & (1 << k) != 0 {
+                    write!(&mut suffix, "{k}")?;
+                }
+            }
+
+            factors.push((value, suffix));
+        }
+
+        if factors.is_empty() {
+            return write!(f, "0");
+        }
+
+        factors.sort_by_key(|(_, suffix)| suffix.len());
+
+        let display = factors
+            .into_iter()
+            .map(|(value, suffix)| format!("{value}{suffix}"))
+            .join(" + ");
+
+        write!(f, "{display}")
+    }
+}
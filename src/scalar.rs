@@ -0,0 +1,110 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The coefficients of multivectors and blades.
+/// Implemented for the primitive floats and signed integers, and can be implemented
+/// for exact or custom number types as well.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + std::fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty: $zero:literal, $one:literal;)*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+impl_scalar! {
+    f32: 0.0, 1.0;
+    f64: 0.0, 1.0;
+    i8: 0, 1;
+    i16: 0, 1;
+    i32: 0, 1;
+    i64: 0, 1;
+    i128: 0, 1;
+    isize: 0, 1;
+}
+
+/// Scalars with a division and the elementary functions, as required by inverses,
+/// exponentials and everything built on them.
+/// Comparisons against zero use [Float::EPSILON] as the tolerance for rounding errors.
+pub trait Float: Scalar + PartialOrd + Div<Output = Self> {
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    /// The total order of the IEEE 754 floats, which also orders NaN.
+    fn total_cmp(&self, other: &Self) -> std::cmp::Ordering;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                const EPSILON: Self = <$t>::EPSILON;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+
+                fn sinh(self) -> Self {
+                    <$t>::sinh(self)
+                }
+
+                fn cosh(self) -> Self {
+                    <$t>::cosh(self)
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    <$t>::atan2(self, other)
+                }
+
+                fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -1,6 +1,5 @@
-use std::marker::ConstParamTy;
-
-#[derive(PartialEq, Eq, Debug, Clone, Copy, ConstParamTy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "nightly", derive(std::marker::ConstParamTy))]
 pub enum Sign {
     Pos,
     Neg,
@@ -24,10 +23,10 @@ impl Sign {
     }
 }
 
-impl std::ops::Mul<f64> for Sign {
-    type Output = f64;
+impl<T: std::ops::Neg<Output = T>> std::ops::Mul<T> for Sign {
+    type Output = T;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         match self {
             Sign::Pos => rhs,
             Sign::Neg => -rhs,
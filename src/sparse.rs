@@ -0,0 +1,326 @@
+use std::{collections::BTreeMap, fmt::Write, marker::PhantomData};
+
+use itertools::Itertools;
+
+#[cfg(feature = "nightly")]
+use crate::mv::MV;
+use crate::{
+    algebra::Signature, basis::Product, bitmask::NonzeroBitBasis, maybe::Maybe::Just,
+    scalar::Scalar, sign::Sign,
+};
+
+/// A multivector which only stores its nonzero coefficients, keyed by the bitmask
+/// [NonzeroBitBasis::unit] of their basis blades.
+/// Products iterate over the pairs of stored coefficients and compute the basis of each
+/// with bitwise operations, such that neither the `2^DIM` coefficients of [MV] nor its
+/// [crate::CayleyTable] of `4^DIM` entries are needed. Coefficients which become zero are removed.
+pub struct SparseMV<A: Signature, T = f64>(BTreeMap<u64, T>, PhantomData<A>);
+
+impl<A: Signature, T: Scalar> SparseMV<A, T> {
+    pub const ZERO: Self = SparseMV(BTreeMap::new(), PhantomData);
+
+    pub fn scalar(value: T) -> Self {
+        Self::ZERO.with(0, value)
+    }
+
+    pub fn blade(value: T, basis: NonzeroBitBasis) -> Self {
+        Self::ZERO.with(basis.unit, basis.sign * value)
+    }
+
+    pub fn get(&self, basis: NonzeroBitBasis) -> T {
+        let value = self.0.get(&basis.unit).copied().unwrap_or(T::ZERO);
+        basis.sign * value
+    }
+
+    /// The number of nonzero coefficients.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The nonzero coefficients with their basis blades in ascending order of the bitmasks.
+    pub fn iter(&self) -> impl Iterator<Item = (NonzeroBitBasis, T)> + '_ {
+        self.0.iter().map(|(&unit, &value)| {
+            let basis = NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit,
+            };
+            (basis, value)
+        })
+    }
+
+    pub fn scale(&self, rhs: T) -> Self {
+        self.iter().fold(Self::ZERO, |result, (basis, value)| {
+            result.with(basis.unit, value * rhs)
+        })
+    }
+
+    /// The grade `k` part `⟨X⟩ₖ`, see [MV::grade].
+    pub fn grade(&self, k: usize) -> Self {
+        self.iter()
+            .filter(|(basis, _)| basis.grade() == k)
+            .fold(Self::ZERO, |result, (basis, value)| {
+                result.with(basis.unit, value)
+            })
+    }
+
+    pub fn product(&self, rhs: &Self, product: Product) -> Self {
+        let mut result = Self::ZERO;
+        for (lhs, a) in self.iter() {
+            for (rhs, b) in rhs.iter() {
+                if let Just(basis) = lhs.product(rhs, product, A::BIT_METRIC) {
+                    result = result.with(basis.unit, basis.sign * (a * b));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn geometric(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Geometric)
+    }
+
+    pub fn exterior(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Exterior)
+    }
+
+    pub fn regressive(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Regressive)
+    }
+
+    pub fn left_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::LeftContraction)
+    }
+
+    pub fn right_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::RightContraction)
+    }
+
+    pub fn inner(&self, rhs: &Self) -> Self {
+        self.product(rhs, Product::Inner)
+    }
+
+    pub fn scalar_product(&self, rhs: &Self) -> T {
+        self.product(rhs, Product::Scalar).get(NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit: 0,
+        })
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.map_basis(NonzeroBitBasis::reverse)
+    }
+
+    pub fn involute(&self) -> Self {
+        self.map_basis(NonzeroBitBasis::involute)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        self.map_basis(NonzeroBitBasis::conjugate)
+    }
+
+    /// See [crate::NonzeroBasis::dual].
+    pub fn dual(&self) -> Self {
+        self.map_basis(|basis| basis.dual(A::BIT_METRIC))
+    }
+
+    fn map_basis(&self, map: impl Fn(NonzeroBitBasis) -> NonzeroBitBasis) -> Self {
+        self.iter().fold(Self::ZERO, |result, (basis, value)| {
+            let basis = map(basis);
+            result.with(basis.unit, basis.sign * value)
+        })
+    }
+
+    /// Adds `value` to the coefficient of `unit`, removing it if it becomes zero.
+    fn with(mut self, unit: u64, value: T) -> Self {
+        let sum = self.0.get(&unit).copied().unwrap_or(T::ZERO) + value;
+        if sum == T::ZERO {
+            self.0.remove(&unit);
+        } else {
+            self.0.insert(unit, sum);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Signature, T: Scalar> From<MV<A, T>> for SparseMV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn from(mv: MV<A, T>) -> Self {
+        (0..A::BASIS_BLADE_COUNT).fold(Self::ZERO, |result, i| result.with(i as u64, mv.0[i]))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Signature, T: Scalar> From<SparseMV<A, T>> for MV<A, T>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    fn from(sparse: SparseMV<A, T>) -> Self {
+        let mut mv = MV::ZERO;
+        for (unit, value) in sparse.0 {
+            mv.0[unit as usize] = value;
+        }
+        mv
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Add for SparseMV<A, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        rhs.iter().fold(self, |result, (basis, value)| {
+            result.with(basis.unit, value)
+        })
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Sub for SparseMV<A, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        rhs.iter().fold(self, |result, (basis, value)| {
+            result.with(basis.unit, -value)
+        })
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Neg for SparseMV<A, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SparseMV(
+            self.0
+                .into_iter()
+                .map(|(unit, value)| (unit, -value))
+                .collect(),
+            PhantomData,
+        )
+    }
+}
+
+/// `A * B`: Geometric product
+impl<A: Signature, T: Scalar> std::ops::Mul for SparseMV<A, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.geometric(&rhs)
+    }
+}
+
+/// `A ^ B`: Exterior product
+impl<A: Signature, T: Scalar> std::ops::BitXor for SparseMV<A, T> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.exterior(&rhs)
+    }
+}
+
+/// `A & B`: Regressive product
+impl<A: Signature, T: Scalar> std::ops::BitAnd for SparseMV<A, T> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.regressive(&rhs)
+    }
+}
+
+/// `A | B`: Inner product
+impl<A: Signature, T: Scalar> std::ops::BitOr for SparseMV<A, T> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.inner(&rhs)
+    }
+}
+
+/// `A >> B`: Contraction of `A` onto `B`
+impl<A: Signature, T: Scalar> std::ops::Shr for SparseMV<A, T> {
+    type Output = Self;
+
+    fn shr(self, rhs: Self) -> Self {
+        self.left_contraction(&rhs)
+    }
+}
+
+/// `A << B`: Contraction of `A` by `B`
+impl<A: Signature, T: Scalar> std::ops::Shl for SparseMV<A, T> {
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self {
+        self.right_contraction(&rhs)
+    }
+}
+
+/// `!A`: Poincaré dual
+impl<A: Signature, T: Scalar> std::ops::Not for SparseMV<A, T> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.dual()
+    }
+}
+
+impl<A: Signature, T: Scalar> std::ops::Mul<T> for SparseMV<A, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.scale(rhs)
+    }
+}
+
+// Deriving these would require `A` to implement them.
+
+impl<A: Signature, T: Scalar> Clone for SparseMV<A, T> {
+    fn clone(&self) -> Self {
+        SparseMV(self.0.clone(), PhantomData)
+    }
+}
+
+impl<A: Signature, T: Scalar> PartialEq for SparseMV<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A: Signature, T: Scalar> std::fmt::Debug for SparseMV<A, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("SparseMV").field(&self.0).finish()
+    }
+}
+
+/// Formats like [MV], ordered by grade.
+impl<A: Signature, T: Scalar + std::fmt::Display> std::fmt::Display for SparseMV<A, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut factors = Vec::new();
+        for (basis, value) in self.iter() {
+            let mut suffix = String::new();
+            if basis.unit != 0 {
+                write!(&mut suffix, "e")?;
+            }
+            for k in (0..A::DIM).filter(|k| basis.unit & (1 << k) != 0) {
+                write!(&mut suffix, "{k}")?;
+            }
+            factors.push((value, suffix));
+        }
+
+        factors.sort_by_key(|(_, suffix)| suffix.len());
+
+        let display = factors
+            .into_iter()
+            .map(|(value, suffix)| format!("{value}{suffix}"))
+            .join(" + ");
+
+        write!(f, "{display}")
+    }
+}
@@ -1,12 +1,37 @@
 use crate::{
-    algebra,
-    basis::NonzeroBasis,
+    algebra::{self, Algebra, Signature},
+    basis::{NonzeroBasis, Product},
+    bitmask::{BitBasis, BitMetric, NonzeroBitBasis},
+    blade::Blade,
+    error::Error,
+    form::BilinearForm,
+    graded::{Bivector, Even, Graded, Grades, Odd, Pseudoscalar, Trivector, Vector},
     maybe::Maybe::{Just, Nothing},
+    metric::Metric,
+    mv::MV,
     sign::Sign,
+    sparse::SparseMV,
 };
 
 type GA = algebra::Complex;
 
+const PRODUCTS: [Product; 14] = [
+    Product::Geometric,
+    Product::Exterior,
+    Product::Regressive,
+    Product::LeftContraction,
+    Product::RightContraction,
+    Product::Inner,
+    Product::HestenesInner,
+    Product::LeftAntiContraction,
+    Product::RightAntiContraction,
+    Product::Scalar,
+    Product::Commutator,
+    Product::AntiCommutator,
+    Product::AntiGeometric,
+    Product::AntiScalar,
+];
+
 #[test]
 fn test_complex_basis() {
     assert_eq!(GA::DIM, 1);
@@ -75,3 +100,1480 @@ fn main() {
     println!("{a} | {b} = {}", a.inner(b, metric));
     println!("{a} * {b} = {}", a.scalar(b, metric));
 }
+
+/// Deterministic pseudo-random multivector with small integer coefficients,
+/// such that products can be compared exactly.
+fn sample<A: Signature>(seed: usize) -> MV<A>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    MV(std::array::from_fn(|i| {
+        ((seed * 7 + i * 13 + seed * i * 5) % 9) as f64 - 4.0
+    }))
+}
+
+#[test]
+fn test_vga3_multivector_products() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let (one, e0, e1, e2) = (e(0b000), e(0b001), e(0b010), e(0b100));
+    let (e01, e12, e012) = (e(0b011), e(0b110), e(0b111));
+
+    assert_eq!(e0.geometric(&e1), e01);
+    assert_eq!(e1.geometric(&e0), -e01);
+    assert_eq!(e01.geometric(&e01), -one);
+    assert_eq!(e0.exterior(&e1).exterior(&e2), e012);
+    assert_eq!(e0.exterior(&e0), MV::ZERO);
+    assert_eq!(e1.left_contraction(&e12), e2);
+    assert_eq!(e12.left_contraction(&e1), MV::ZERO);
+    assert_eq!(e12.right_contraction(&e2), e1);
+    assert_eq!(e12.inner(&e1), -e2);
+    assert_eq!(e12.scalar_product(&e12.reverse()), 1.0);
+    assert_eq!(e01.regressive(&e12), e1);
+    assert_eq!(e0.right_complement(), e12);
+    assert_eq!(e1.hodge(), -e(0b101));
+
+    let a = e0 * 2.0 + e1;
+    let b = e1 - e2 * 3.0;
+    assert_eq!(a.geometric(&b), a.inner(&b) + a.exterior(&b));
+    assert_eq!(a.geometric(&b).scalar_product(&one), a.scalar_product(&b));
+}
+
+#[test]
+fn test_geometric_product_is_associative() {
+    fn check<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        let (a, b, c) = (sample::<A>(1), sample::<A>(2), sample::<A>(3));
+        assert_eq!(a.geometric(&b).geometric(&c), a.geometric(&b.geometric(&c)));
+        assert_eq!(a.exterior(&b).exterior(&c), a.exterior(&b.exterior(&c)));
+    }
+
+    check::<algebra::Complex>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<algebra::CGA3>();
+}
+
+#[test]
+fn test_multivector_display() {
+    type GA = algebra::PGA2;
+
+    assert_eq!(format!("{}", MV::<GA>::ZERO), "0");
+    let mv = MV::<GA>::scalar(1.5)
+        + MV::blade(2.0, NonzeroBasis::from_index(0b101))
+        + MV::blade(-1.0, NonzeroBasis::from_index(0b010));
+    assert_eq!(format!("{mv}"), "1.5 + -1e1 + 2e02");
+}
+
+#[test]
+fn test_cayley_tables_match_basis_products() {
+    fn check<A: Signature>()
+    where
+        [(); A::DIM]:,
+        [(); A::BASIS_BLADE_COUNT]:,
+    {
+        let metric = A::metric();
+        for product in PRODUCTS {
+            let table = A::cayley(product);
+            for i in 0..A::BASIS_BLADE_COUNT {
+                for j in 0..A::BASIS_BLADE_COUNT {
+                    let lhs = NonzeroBasis::<{ A::DIM }>::from_index(i);
+                    let rhs = NonzeroBasis::from_index(j);
+                    let expected = match lhs.product(rhs, product, metric) {
+                        Just(basis) => Just((basis.index(), basis.sign)),
+                        Nothing => Nothing,
+                    };
+                    assert_eq!(table.0[i][j], expected, "{product:?} of {lhs} and {rhs}");
+                }
+            }
+        }
+    }
+
+    check::<algebra::Complex>();
+    check::<algebra::VGA2>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA2>();
+    check::<algebra::PGA3>();
+    check::<algebra::CGA3>();
+    check::<Algebra<1, 3, 0>>();
+}
+
+#[test]
+fn test_bitmask_basis_matches_array_basis() {
+    fn check<A: Signature>()
+    where
+        [(); A::DIM]:,
+    {
+        let metric = A::metric();
+        let bit_metric = BitMetric::new(metric);
+        for i in 0..A::BASIS_BLADE_COUNT {
+            let lhs = NonzeroBasis::<{ A::DIM }>::from_index(i).neg();
+            let bit_lhs = NonzeroBitBasis::from_array(lhs);
+            assert_eq!(bit_lhs.to_array(), lhs);
+            assert_eq!(bit_lhs.grade(), lhs.grade());
+            assert_eq!(bit_lhs.reverse().to_array(), lhs.reverse());
+            assert_eq!(bit_lhs.involute().to_array(), lhs.involute());
+            assert_eq!(
+                bit_lhs.right_complement(bit_metric).to_array(),
+                lhs.right_complement()
+            );
+            assert_eq!(
+                bit_lhs.left_complement(bit_metric).to_array(),
+                lhs.left_complement()
+            );
+            assert_eq!(
+                match bit_lhs.hodge(bit_metric) {
+                    Just(basis) => Just(basis.to_array()),
+                    Nothing => Nothing,
+                },
+                lhs.hodge(metric)
+            );
+            assert_eq!(bit_lhs.dual(bit_metric).to_array(), lhs.dual(metric));
+
+            for j in 0..A::BASIS_BLADE_COUNT {
+                let rhs = NonzeroBasis::from_index(j);
+                let bit_rhs = NonzeroBitBasis::from_array(rhs);
+                for product in PRODUCTS {
+                    let expected = match lhs.product(rhs, product, metric) {
+                        Just(basis) => Just(NonzeroBitBasis::from_array(basis)),
+                        Nothing => Nothing,
+                    };
+                    assert_eq!(
+                        bit_lhs.product(bit_rhs, product, bit_metric),
+                        expected,
+                        "{product:?} of {lhs} and {rhs}"
+                    );
+                }
+            }
+        }
+    }
+
+    check::<algebra::Complex>();
+    check::<algebra::Dual>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<algebra::CGA3>();
+    check::<Algebra<1, 3, 0>>();
+}
+
+#[test]
+fn test_bit_metric_signature() {
+    fn check<const N: usize>(p: usize, q: usize) {
+        let r = N - p - q;
+        assert_eq!(
+            BitMetric::signature(p, q, r),
+            BitMetric::new(Metric::<N>::signature(p, q))
+        );
+    }
+    check::<5>(4, 1);
+    check::<4>(3, 0);
+    check::<64>(64, 0);
+    check::<64>(0, 64);
+    check::<64>(0, 0);
+    check::<64>(32, 16);
+    check::<64>(63, 1);
+    assert_eq!(BitMetric::signature(64, 0, 0).pseudoscalar(), u64::MAX);
+    assert_eq!(BitMetric::signature(0, 0, 0).pseudoscalar(), 0);
+}
+
+#[test]
+fn test_duality_operators() {
+    fn check<A: Signature>()
+    where
+        [(); A::DIM]:,
+    {
+        let metric = A::metric();
+        let i = Just(NonzeroBasis::<{ A::DIM }>::I);
+        for index in 0..A::BASIS_BLADE_COUNT {
+            for sign in [Sign::Pos, Sign::Neg] {
+                let a = NonzeroBasis::<{ A::DIM }>::from_index(index);
+                let a = NonzeroBasis { sign, ..a };
+                let right = a.right_complement();
+                let left = a.left_complement();
+
+                assert_eq!(a.exterior(right, metric), i, "{a} ∧ right({a}) = I");
+                assert_eq!(left.exterior(a, metric), i, "left({a}) ∧ {a} = I");
+                assert_eq!(right.left_complement(), a);
+                assert_eq!(left.right_complement(), a);
+
+                let norm = a.reverse().scalar(a, metric);
+                let norm = match norm {
+                    Just(b) => Just(NonzeroBasis {
+                        sign: b.sign,
+                        ..NonzeroBasis::I
+                    }),
+                    Nothing => Nothing,
+                };
+                assert_eq!(
+                    Just(a).exterior(a.hodge(metric), metric),
+                    norm,
+                    "{a} ∧ ⋆{a} = ({a}~ * {a}) I"
+                );
+                if let Just(hodge) = a.hodge(metric) {
+                    assert_eq!(a.dual(metric), hodge);
+                }
+                assert_eq!(
+                    a.dual(metric).grade(),
+                    a.anti_grade(),
+                    "The dual never vanishes"
+                );
+
+                assert_eq!(a.regressive(NonzeroBasis::I, metric), Just(a));
+                assert_eq!(NonzeroBasis::I.regressive(a, metric), Just(a));
+            }
+        }
+    }
+
+    check::<algebra::VGA2>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<algebra::CGA3>();
+}
+
+#[test]
+fn test_vga2_complements() {
+    type GA = algebra::VGA2;
+    let metric = GA::metric();
+
+    let e = |index| NonzeroBasis::<2>::from_index(index);
+    assert_eq!(e(0b01).right_complement(), e(0b10));
+    assert_eq!(e(0b10).right_complement(), e(0b01).neg());
+    assert_eq!(e(0b01).left_complement(), e(0b10).neg());
+    assert_eq!(e(0b10).left_complement(), e(0b01));
+    assert_eq!(e(0b01).regressive(e(0b10), metric), Just(NonzeroBasis::ONE));
+    assert_eq!(
+        e(0b10).regressive(e(0b01), metric),
+        Just(NonzeroBasis::ONE.neg())
+    );
+}
+
+#[test]
+fn test_pga3_duality() {
+    type GA = algebra::PGA3;
+    let metric = GA::metric();
+
+    // The degenerate basis vector is the last one.
+    let e3 = NonzeroBasis::<4>::from_index(0b1000);
+    let e012 = NonzeroBasis::<4>::from_index(0b0111);
+    assert_eq!(e3.hodge(metric), Nothing);
+    assert_eq!(e3.dual(metric), e012.neg());
+    assert_eq!(e3.right_complement(), e012.neg());
+    assert_eq!(e012.dual(metric), e3);
+}
+
+#[test]
+fn test_blades() {
+    const E0: BitBasis = BitBasis::unit(0b001);
+    const E1: BitBasis = BitBasis::unit(0b010);
+    const E01: BitBasis = BitBasis::unit(0b011);
+    const E10: BitBasis = BitBasis::unit(0b011).neg();
+
+    let e0 = Blade::<algebra::VGA3, E0>::new(2.0);
+    let e1 = Blade::<algebra::VGA3, E1>::new(3.0);
+    let e01: Blade<algebra::VGA3, E01> = e0.geometric(e1);
+    assert_eq!(e01.0, 6.0);
+    let e10: Blade<algebra::VGA3, E10> = e1.exterior(e0);
+    assert_eq!(e10.scale(), -6.0);
+    let one: Blade<algebra::VGA3, { BitBasis::unit(0) }> = e0.inner(e0);
+    assert_eq!(one.scale(), 4.0);
+    let zero: Blade<algebra::VGA3, { Nothing }> = e0.exterior(e0);
+    assert_eq!(zero.scale(), 0.0);
+    let contracted: Blade<algebra::VGA3, E1> = e0.left_contraction(e01);
+    assert_eq!(contracted.scale(), 12.0);
+
+    // The degenerate dimension of PGA is the last one.
+    let e3 = Blade::<algebra::PGA3, { BitBasis::unit(0b1000) }>::ONE;
+    let zero: Blade<algebra::PGA3, { Nothing }> = e3.geometric(e3);
+    assert_eq!(zero.scale(), 0.0);
+
+    // The negative dimension of CGA follows the positive ones.
+    let e4 = Blade::<algebra::CGA3, { BitBasis::unit(0b10000) }>::ONE;
+    let minus_one: Blade<algebra::CGA3, { BitBasis::unit(0).neg() }> = e4.geometric(e4);
+    assert_eq!(minus_one.scale(), -1.0);
+    assert_eq!((-e4).scale(), -1.0);
+}
+
+#[test]
+fn test_multivector_operators() {
+    type GA = algebra::PGA3;
+
+    let (a, b) = (sample::<GA>(4), sample::<GA>(5));
+    assert_eq!(a * b, a.geometric(&b));
+    assert_eq!(a ^ b, a.exterior(&b));
+    assert_eq!(a & b, a.regressive(&b));
+    assert_eq!(a | b, a.inner(&b));
+    assert_eq!(a >> b, a.left_contraction(&b));
+    assert_eq!(a << b, a.right_contraction(&b));
+    assert_eq!(!a, a.dual());
+    assert_eq!(-a, a * -1.0);
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let u = e(0b0001) + e(0b0100) * 2.0 + e(0b1000);
+    let v = e(0b0010) * 3.0 - e(0b0100) + e(0b1000) * 5.0;
+    assert_eq!(
+        u * v - (u ^ v),
+        u | v,
+        "The geometric product of vectors splits"
+    );
+}
+
+#[test]
+fn test_blade_operators() {
+    type GA = algebra::VGA3;
+
+    let e0 = Blade::<GA, { BitBasis::unit(0b001) }>::new(2.0);
+    let e1 = Blade::<GA, { BitBasis::unit(0b010) }>::new(3.0);
+
+    let e01: Blade<GA, { BitBasis::unit(0b011) }> = e0 * e1;
+    assert_eq!(e01.0, 6.0);
+    let e01: Blade<GA, { BitBasis::unit(0b011) }> = e0 ^ e1;
+    assert_eq!(e01.0, 6.0);
+    let zero: Blade<GA, { Nothing }> = e0 | e1;
+    assert_eq!(zero.scale(), 0.0);
+    let e1: Blade<GA, { BitBasis::unit(0b010) }> = e0 >> e01;
+    assert_eq!(e1.0, 12.0);
+    let e0: Blade<GA, { BitBasis::unit(0b001) }> = e01 << e1;
+    assert_eq!(e0.0, 72.0);
+    let e2: Blade<GA, { BitBasis::unit(0b100) }> = !e01;
+    assert_eq!(e2.0, 6.0);
+    let one: Blade<GA, { BitBasis::unit(0) }> = e2 & !e2;
+    assert_eq!((-one).scale(), -36.0);
+}
+
+#[test]
+fn test_scalar_types() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA, i64>::blade(1, NonzeroBasis::from_index(index));
+    let (e0, e1, e01) = (e(0b001), e(0b010), e(0b011));
+    assert_eq!(e0 * e1, e01);
+    assert_eq!(e01 * e01, MV::scalar(-1));
+    assert_eq!((e0 * 2 + e1).scalar_product(&(e0 * 3)), 6);
+    assert_eq!(format!("{}", e0 * 2 - e01), "2e0 + -1e01");
+
+    let v = MV::<GA, f32>::blade(0.5, NonzeroBasis::from_index(0b100));
+    assert_eq!(v * v, MV::scalar(0.25f32));
+    assert_eq!(Sign::Neg * 2i32, -2);
+
+    let e0 = Blade::<GA, { BitBasis::unit(0b001) }, i32>::new(2);
+    let e1 = Blade::<GA, { BitBasis::unit(0b010) }, i32>::new(3);
+    let e10: Blade<GA, { BitBasis::unit(0b011).neg() }, i32> = e1 ^ e0;
+    assert_eq!(e10.scale(), -6);
+    assert_eq!(Blade::<GA, { Nothing }, f32>::ONE.scale(), 0.0);
+}
+
+/// Asserts that all coefficients agree up to rounding errors.
+fn assert_close<A: Signature>(lhs: MV<A>, rhs: MV<A>)
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    for i in 0..A::BASIS_BLADE_COUNT {
+        assert!((lhs.0[i] - rhs.0[i]).abs() < 1e-9, "{lhs} != {rhs}");
+    }
+}
+
+/// Asserts that `lhs` is a nonzero multiple of `rhs` up to rounding errors.
+fn assert_proportional<A: Signature>(lhs: MV<A>, rhs: MV<A>)
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    let largest = (0..A::BASIS_BLADE_COUNT)
+        .max_by(|&i, &j| rhs.0[i].abs().total_cmp(&rhs.0[j].abs()))
+        .unwrap();
+    let ratio = lhs.0[largest] / rhs.0[largest];
+    assert!(ratio.abs() > 1e-9, "{lhs} is not proportional to {rhs}");
+    assert_close(lhs, rhs.scale(ratio));
+}
+
+#[test]
+fn test_inverse() {
+    fn check<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for seed in 0..8 {
+            // The offset avoids zero, which is the only non-invertible sample of [algebra::Real].
+            let a = sample::<A>(seed) + MV::scalar(0.5);
+            let inverse = a.inverse().unwrap();
+            assert_close(a * inverse, MV::scalar(1.0));
+            assert_close(inverse * a, MV::scalar(1.0));
+            assert_close(inverse, a.inverse_by_elimination().unwrap());
+        }
+    }
+
+    check::<algebra::Real>();
+    check::<algebra::Complex>();
+    check::<algebra::VGA2>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<Algebra<2, 2, 0>>();
+    check::<algebra::CGA3>();
+    check::<Algebra<6, 0, 0>>();
+}
+
+#[test]
+fn test_inverse_of_non_invertible_elements() {
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert_eq!(e(0b1000).inverse(), Err(Error::NotInvertible));
+    assert_eq!(e(0b1000).versor_inverse(), Err(Error::NotInvertible));
+    assert_eq!(
+        e(0b1001).inverse_by_elimination(),
+        Err(Error::NotInvertible)
+    );
+
+    let e = |index| MV::<algebra::CGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let null = e(0b01000) + e(0b10000);
+    assert_eq!(null.inverse(), Err(Error::NotInvertible));
+    assert_eq!((e(0) + null).inverse(), Ok(e(0) - null));
+    assert_eq!(
+        MV::<algebra::CGA3>::ZERO.inverse(),
+        Err(Error::NotInvertible)
+    );
+}
+
+#[test]
+fn test_versor_inverse() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let rotor = e(0) * 3.0 + e(0b011) * 4.0;
+    assert_close(
+        rotor.versor_inverse().unwrap(),
+        (e(0) * 3.0 - e(0b011) * 4.0) * 0.04,
+    );
+    let reflection = (e(0b001) + e(0b100)) * 2.0;
+    assert_close(reflection * reflection.versor_inverse().unwrap(), e(0));
+
+    let e01 = Blade::<GA, { BitBasis::unit(0b011) }>::new(4.0);
+    assert_eq!(e01.inverse().unwrap().0, -0.25);
+    let e3 = Blade::<algebra::PGA3, { BitBasis::unit(0b1000) }>::ONE;
+    assert_eq!(e3.inverse().unwrap_err(), Error::NotInvertible);
+
+    // The tolerance scales with the magnitude, such that tiny elements stay invertible.
+    let tiny = e(0b001) * 1e-9;
+    assert_close(tiny.inverse().unwrap() * 1e-9, e(0b001));
+    assert_close(tiny.versor_inverse().unwrap() * 1e-9, e(0b001));
+    let tiny = Blade::<GA, { BitBasis::unit(0b001) }>::new(1e-9);
+    assert!((tiny.inverse().unwrap().0 * 1e-9 - 1.0).abs() < 1e-12);
+    let tiny_rotor = rotor * 1e-9;
+    assert_close(
+        tiny_rotor.inverse().unwrap() * 1e-9,
+        rotor.inverse().unwrap(),
+    );
+    assert_close(
+        tiny_rotor.versor_inverse().unwrap() * 1e-9,
+        rotor.versor_inverse().unwrap(),
+    );
+}
+
+#[test]
+fn test_projection_and_rejection() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let (e0, e1, e2) = (e(0b001), e(0b010), e(0b100));
+    let x = e0 + e1 * 2.0 + e2 * 3.0;
+
+    // The norm of the blade must not affect the result.
+    let plane = (e0 ^ e1) * 3.0;
+    assert_eq!(x.project(&plane), Ok(e0 + e1 * 2.0));
+    assert_eq!(x.reject(&plane), Ok(e2 * 3.0));
+    assert_eq!(x.project(&(e0 * 2.0)), Ok(e0));
+    assert_eq!(x.reject(&(e0 * 2.0)), Ok(e1 * 2.0 + e2 * 3.0));
+
+    let diagonal = e0 + e1;
+    assert_eq!(x.project(&diagonal), Ok((e0 + e1) * 1.5));
+    assert_eq!(x.reject(&diagonal), Ok((e0 - e1) * -0.5 + e2 * 3.0));
+
+    let tilted = e(0b011) + e(0b110);
+    assert_eq!(tilted.project(&plane), Ok(e(0b011)));
+    assert_eq!(plane.project(&e2), Ok(MV::ZERO));
+
+    // Lines through the origin of PGA3 are invertible, the ideal plane `e3` is not.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let plane = e(0b0001) + e(0b0010) + e(0b1000);
+    assert_eq!(plane.project(&(e(0b0011) * 2.0)), Ok(e(0b0001) + e(0b0010)));
+    assert_eq!(plane.reject(&e(0b0011)), Ok(e(0b1000)));
+    assert_eq!(plane.project(&e(0b1000)), Err(Error::NotInvertible));
+    assert_eq!(plane.reject(&MV::ZERO), Err(Error::NotInvertible));
+
+    let e0 = Blade::<GA, { BitBasis::unit(0b001) }>::new(2.0);
+    let e01 = Blade::<GA, { BitBasis::unit(0b011) }>::new(4.0);
+    let projected: Blade<GA, { BitBasis::unit(0b001).neg() }> = e0.project(e01).unwrap();
+    assert_eq!(projected.scale(), 2.0);
+    let rejected: Blade<GA, { Nothing }> = e0.reject(e01).unwrap();
+    assert_eq!(rejected.scale(), 0.0);
+    let e2 = Blade::<GA, { BitBasis::unit(0b100) }>::new(3.0);
+    let rejected: Blade<GA, { BitBasis::unit(0b100).neg() }> = e2.reject(e01).unwrap();
+    assert_eq!(rejected.scale(), 3.0);
+    let e3 = Blade::<algebra::PGA3, { BitBasis::unit(0b1000) }>::ONE;
+    assert!(
+        Blade::<algebra::PGA3, { BitBasis::unit(0b0001) }>::ONE
+            .project(e3)
+            .is_err()
+    );
+}
+
+/// Deterministic bivector whose rotation angles are small enough for a unique logarithm.
+fn sample_bivector<A: Signature>(seed: usize) -> MV<A>
+where
+    [(); A::BASIS_BLADE_COUNT]:,
+{
+    let sample = sample::<A>(seed);
+    MV(std::array::from_fn(|i| {
+        if i.count_ones() == 2 {
+            sample.0[i] / 16.0
+        } else {
+            0.0
+        }
+    }))
+}
+
+#[test]
+fn test_exp_and_log() {
+    fn check<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for seed in 0..8 {
+            let bivector = sample_bivector::<A>(seed);
+            let exp = bivector.exp();
+            assert_close(exp, bivector.exp_by_series());
+            assert_close(exp * exp.reverse(), MV::scalar(1.0));
+            assert_close(exp.log().unwrap(), bivector);
+            assert_close(
+                (bivector + MV::scalar(0.5)).exp().log().unwrap(),
+                bivector + MV::scalar(0.5),
+            );
+        }
+    }
+
+    check::<algebra::VGA2>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA2>();
+    check::<algebra::PGA3>();
+    check::<Algebra<4, 0, 0>>();
+    check::<Algebra<1, 3, 0>>();
+    check::<algebra::CGA3>();
+}
+
+#[test]
+fn test_exp_closed_forms() {
+    fn generator<A: Signature>() -> MV<A>
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        MV::blade(0.5, NonzeroBasis::from_index(1))
+    }
+
+    let (cos, sin) = (0.5f64.cos(), 0.5f64.sin());
+    let exp = generator::<algebra::Complex>().exp();
+    assert_close(exp, MV([cos, sin]));
+    assert_close(exp.log().unwrap(), generator());
+
+    let (cosh, sinh) = (0.5f64.cosh(), 0.5f64.sinh());
+    let exp = generator::<algebra::Hyperbolic>().exp();
+    assert_close(exp, MV([cosh, sinh]));
+    assert_close(exp.log().unwrap(), generator());
+
+    let exp = generator::<algebra::Dual>().exp();
+    assert_eq!(exp, MV([1.0, 0.5]));
+    assert_eq!(exp.log(), Ok(generator()));
+
+    // A screw motion in PGA3, rotating around the `e2` axis and translating along it.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let screw = e(0b0011) * 0.25 + e(0b1100) * 2.0;
+    assert_close(
+        screw.exp(),
+        (e(0b0011) * 0.25).exp() * (e(0) + e(0b1100) * 2.0),
+    );
+    assert_close(screw.exp().log().unwrap(), screw);
+
+    // The rounding errors of `B²` grow with `|B|²`, which must not rule out the closed forms
+    // for simple bivectors with large translations or angles.
+    let plane = |x: f64, y: f64, z: f64, w: f64| {
+        e(0b0001) * x + e(0b0010) * y + e(0b0100) * z + e(0b1000) * w
+    };
+    let line = plane(0.3, 0.5, -0.2, 40.1) ^ plane(-0.4, 0.1, 0.6, -70.3);
+    assert!((line * line).0[0] < 0.0);
+    assert_close(line.exp().log().unwrap(), line);
+    // Without the closed form, bivectors of 6 dimensions have no logarithm.
+    type V = Algebra<6, 0, 0>;
+    let vector = |coefficients: [f64; 6]| {
+        (0..6).fold(MV::<V>::ZERO, |vector, i| {
+            vector + MV::blade(coefficients[i], NonzeroBasis::from_index(1 << i))
+        })
+    };
+    let bivector =
+        vector([1.1, 2.3, -0.7, 0.4, 0.8, -1.3]) ^ vector([-0.3, 0.9, 1.7, -2.1, 0.2, 0.5]);
+    let unit = bivector * (1.0 / bivector.norm());
+    let angle = 100.0 - 32.0 * std::f64::consts::PI;
+    assert_close((unit * 100.0).exp(), (unit * angle).exp());
+    assert_close((unit * 100.0).exp().log().unwrap(), unit * angle);
+
+    // The norms of scaled rotors are small, but positive.
+    let e = |index| MV::<algebra::VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let rotor = (e(0b011) * 0.3).exp() * 1e-9;
+    assert_close(
+        rotor.log().unwrap(),
+        e(0b011) * 0.3 + MV::scalar(1e-9f64.ln()),
+    );
+    let e = |index| MV::<Algebra<4, 0, 0>>::blade(1.0, NonzeroBasis::from_index(index));
+    let bivector = e(0b0011) * 0.3 + e(0b1100) * 1.1;
+    assert_close(
+        (bivector.exp() * 1e-9).log().unwrap(),
+        bivector + MV::scalar(1e-9f64.ln()),
+    );
+}
+
+#[test]
+fn test_rotor_interpolation() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let start = (e(0b011) * 0.25).exp();
+    let end = (e(0b011) * 0.25 + e(0b110) * 0.5).exp() * start;
+    assert_close(start.interpolate(&end, 0.0).unwrap(), start);
+    assert_close(start.interpolate(&end, 1.0).unwrap(), end);
+    assert_close(
+        start.interpolate(&end, 0.5).unwrap(),
+        (e(0b011) * 0.125 + e(0b110) * 0.25).exp() * start,
+    );
+
+    assert_eq!(MV::<GA>::scalar(-1.0).log(), Err(Error::NoLogarithm));
+    assert_eq!(MV::<GA>::ZERO.log(), Err(Error::NoLogarithm));
+    assert_eq!(e(0b001).log(), Err(Error::NoLogarithm));
+}
+
+#[test]
+fn test_grade_queries() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let mixed = e(0) * 2.0 + e(0b001) + e(0b010) * 3.0 + e(0b011) * 4.0 + e(0b111) * 5.0;
+    assert_eq!(mixed.grade(0), e(0) * 2.0);
+    assert_eq!(mixed.grade(1), e(0b001) + e(0b010) * 3.0);
+    assert_eq!(mixed.grade(2), e(0b011) * 4.0);
+    assert_eq!(mixed.grade(4), MV::ZERO);
+    assert_eq!(mixed.anti_grade(0), e(0b111) * 5.0);
+    assert_eq!(mixed.anti_grade(1), mixed.grade(2));
+    assert_eq!(mixed.even(), e(0) * 2.0 + e(0b011) * 4.0);
+    assert_eq!(mixed.odd(), mixed - mixed.even());
+    assert_eq!(mixed.grades(), vec![0, 1, 2, 3]);
+    assert_eq!(mixed.odd().grades(), vec![1, 3]);
+    assert_eq!(MV::<GA>::ZERO.grades(), vec![]);
+    assert!(!mixed.is_homogeneous());
+    assert!(mixed.grade(1).is_homogeneous());
+    assert!(MV::<GA>::ZERO.is_homogeneous());
+
+    // Every bivector of 3 dimensions is a blade, but not in 4 dimensions.
+    assert!((e(0b011) + e(0b110) * 2.0).is_blade());
+    assert!(!mixed.is_blade());
+    let e = |index| MV::<Algebra<4, 0, 0>>::blade(1.0, NonzeroBasis::from_index(index));
+    assert!(!(e(0b0011) + e(0b1100)).is_blade());
+    assert!(!((e(0b0011) + e(0b1100)) * 1e-5).is_blade());
+    assert!(!((e(0b0011) + e(0b1100)) * 1e5).is_blade());
+    assert!(((e(0b0011) + e(0b0110)) * 1e-9).is_blade());
+    assert!(((e(0b0001) + e(0b0100)) ^ (e(0b0010) - e(0b1000))).is_blade());
+    assert!(e(0b0111).is_blade());
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert!(!(e(0b0011) + e(0b1100)).is_blade());
+    assert!((e(0b0011) + e(0b1001)).is_blade());
+}
+
+#[test]
+fn test_versor_queries() {
+    let e = |index| MV::<algebra::VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert!((e(0b001) + e(0b010)).is_versor());
+    assert!((e(0) + e(0b011) * 2.0).is_versor());
+    assert!((e(0b011) * 0.3 + e(0b110) * 0.5).exp().is_versor());
+    assert!(!(e(0) + e(0b001)).is_versor());
+    assert!(!MV::<algebra::VGA3>::ZERO.is_versor());
+
+    let e = |index| MV::<Algebra<4, 0, 0>>::blade(1.0, NonzeroBasis::from_index(index));
+    assert!((e(0b0011) * 0.3 + e(0b1100) * 0.5).exp().is_versor());
+    assert!(!(e(0b0011) + e(0b1100)).is_versor());
+    assert!(!((e(0b0011) + e(0b1100)) * 1e-5).is_versor());
+    assert!(!((e(0) + e(0b0011)) * 1e-9 + e(0b0001) * 1e-10).is_versor());
+    assert!(((e(0b0011) * 0.3 + e(0b1100) * 0.5).exp() * 1e-9).is_versor());
+    assert!(!(e(0) + e(0b1111)).is_versor());
+
+    // Motors of PGA3 are versors, null vectors are not invertible.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert!((e(0b0011) * 0.3 + e(0b1001) * 2.0).exp().is_versor());
+    assert!(!e(0b1000).is_versor());
+}
+
+#[test]
+fn test_norms() {
+    let e = |index| MV::<algebra::VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let v = e(0b001) * 3.0 + e(0b100) * 4.0;
+    assert_eq!(v.norm_squared(), 25.0);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.normalized(), Ok(v * 0.2));
+    assert_eq!((e(0b011) * 2.0).norm_squared(), 4.0);
+    assert_eq!(v.weight(), MV::ZERO);
+    assert_eq!(v.weight_norm(), 0.0);
+    assert_eq!(v.unitize(), Err(Error::ZeroNorm));
+    assert_eq!(MV::<algebra::VGA3>::ZERO.normalized(), Err(Error::ZeroNorm));
+    assert_close((e(0b010) * 1e-17).normalized().unwrap(), e(0b010));
+
+    // Negative and null squares of CGA3.
+    let e = |index| MV::<algebra::CGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert_eq!((e(0b10000) * 2.0).norm_squared(), -4.0);
+    assert_eq!((e(0b10000) * 2.0).norm(), 2.0);
+    assert_eq!((e(0b10000) * 2.0).normalized(), Ok(e(0b10000)));
+    let null = e(0b01000) + e(0b10000);
+    assert_eq!(null.norm_squared(), 0.0);
+    assert_eq!(null.normalized(), Err(Error::ZeroNorm));
+
+    let e = |index| MV::<algebra::Complex>::blade(1.0, NonzeroBasis::from_index(index));
+    assert_eq!((e(0) * 3.0 + e(1) * 4.0).norm_squared(), -7.0);
+    assert_eq!(e(1).normalized(), Ok(e(1)));
+
+    // Points of PGA3 are vectors whose weight is the coefficient of the degenerate `e3`.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let point = e(0b0001) * 2.0 + e(0b0010) * 4.0 + e(0b0100) * 6.0 - e(0b1000) * 2.0;
+    assert_eq!(
+        point.bulk(),
+        e(0b0001) * 2.0 + e(0b0010) * 4.0 + e(0b0100) * 6.0
+    );
+    assert_eq!(point.weight(), e(0b1000) * -2.0);
+    assert_eq!(point.bulk_norm(), 56f64.sqrt());
+    assert_eq!(point.bulk_norm(), point.norm());
+    assert_eq!(point.weight_norm(), 2.0);
+    assert_eq!(point.unitize(), Ok(point * 0.5));
+    assert_eq!(point.bulk().unitize(), Err(Error::ZeroNorm));
+    assert_close((point * 1e-17).unitize().unwrap(), point * 0.5);
+    let far = e(0b0001) * 1e12 + e(0b1000);
+    assert_eq!(far.unitize(), Ok(far));
+
+    let line = e(0b0011) * 3.0 + e(0b1001) * 4.0 + e(0b1010) * 12.0;
+    assert_eq!(line.bulk_norm(), 3.0);
+    assert_eq!(line.weight_norm(), 160f64.sqrt());
+}
+
+#[test]
+fn test_sandwich() {
+    type GA = algebra::VGA3;
+
+    let e = |index| MV::<GA>::blade(1.0, NonzeroBasis::from_index(index));
+    let (e0, e1, e2) = (e(0b001), e(0b010), e(0b100));
+
+    // A rotation by `π/2` in the `e01` plane.
+    let rotor = (e(0b011) * -std::f64::consts::FRAC_PI_4).exp();
+    assert_close(rotor.sandwich(&e0).unwrap(), e1);
+    assert_close(rotor.sandwich(&(e0 ^ e2)).unwrap(), e1 ^ e2);
+    assert_close(rotor.transform(&(e0 + e2)).unwrap(), e1 + e2);
+
+    // Reflections in the plane orthogonal to `e0` flip `e0`, the sandwich only conjugates.
+    let mirror = e0 * 2.0;
+    assert_eq!(mirror.transform(&e0), Ok(-e0));
+    assert_eq!(mirror.transform(&e1), Ok(e1));
+    assert_eq!(mirror.transform(&(e0 ^ e1)), Ok(-(e0 ^ e1)));
+    assert_eq!(mirror.sandwich(&e1), Ok(-e1));
+    assert_eq!(MV::<GA>::ZERO.transform(&e1), Err(Error::NotInvertible));
+
+    // Only the grades of `X` are computed, which is exact for versors.
+    for seed in 0..4 {
+        let versor = sample_bivector::<algebra::CGA3>(seed).exp();
+        let x = sample::<algebra::CGA3>(seed + 4);
+        let full = versor * x * versor.versor_inverse().unwrap();
+        assert_close(versor.sandwich(&x).unwrap(), full);
+    }
+
+    // Odd versors with rounding errors in their even part are still reflections.
+    let e = |index| MV::<algebra::CGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let rotor = sample_bivector::<algebra::CGA3>(1).exp();
+    let reflection = rotor * (e(0b00001) * 0.6 + e(0b00100) * 0.8) * rotor.reverse();
+    let noisy = reflection + MV::scalar(1e-17) + e(0b00011) * -2e-17;
+    let x = e(0b00010) + e(0b00011);
+    assert_close(
+        noisy.transform(&x).unwrap(),
+        reflection * x.involute() * reflection.versor_inverse().unwrap(),
+    );
+    assert_eq!((e(0) + e(0b00001)).transform(&x), Err(Error::NotVersor));
+
+    // A translation of PGA3 along `e1` moves the plane `e1` but not the plane `e0`.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let translator = e(0) + e(0b1010);
+    assert_eq!(
+        translator.transform(&e(0b0010)),
+        Ok(e(0b0010) - e(0b1000) * 2.0)
+    );
+    assert_eq!(translator.transform(&e(0b0001)), Ok(e(0b0001)));
+}
+
+#[test]
+fn test_grade_product() {
+    fn check<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for seed in 0..4 {
+            let (a, b) = (sample::<A>(seed), sample::<A>(seed + 4));
+            for product in PRODUCTS {
+                for grades in [vec![], vec![0], vec![1, 2], (0..=A::DIM).collect()] {
+                    let expected = grades
+                        .iter()
+                        .fold(MV::ZERO, |sum, &k| sum + a.product(&b, product).grade(k));
+                    assert_eq!(a.grade_product(&b, product, &grades), expected);
+                }
+            }
+        }
+    }
+
+    check::<algebra::Real>();
+    check::<algebra::Complex>();
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<algebra::CGA3>();
+}
+
+#[test]
+fn test_commutators() {
+    fn check<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for seed in 0..4 {
+            let (a, b) = (sample::<A>(seed), sample::<A>(seed + 4));
+            assert_eq!(a.commutator(&b), (a * b - b * a) * 0.5);
+            assert_eq!(a.anti_commutator(&b), (a * b + b * a) * 0.5);
+            assert_eq!(a.commutator(&b) + a.anti_commutator(&b), a * b);
+
+            // Bivectors are closed under the commutator, which satisfies the Jacobi identity.
+            let (x, y, z) = (a.grade(2), b.grade(2), sample::<A>(seed + 8).grade(2));
+            assert_eq!(
+                x.commutator(&y).grades(),
+                x.commutator(&y).grade(2).grades()
+            );
+            let jacobi = x.commutator(&y.commutator(&z))
+                + y.commutator(&z.commutator(&x))
+                + z.commutator(&x.commutator(&y));
+            assert_eq!(jacobi, MV::ZERO);
+        }
+    }
+
+    check::<algebra::VGA3>();
+    check::<algebra::PGA3>();
+    check::<Algebra<1, 3, 0>>();
+
+    let e = |index| NonzeroBasis::<3>::from_index(index);
+    let metric = algebra::VGA3::metric();
+    assert_eq!(e(0b011).commutator(e(0b110), metric), Just(e(0b101)));
+    assert_eq!(e(0b011).anti_commutator(e(0b110), metric), Nothing);
+    assert_eq!(e(0b011).commutator(e(0b011), metric), Nothing);
+    assert_eq!(e(0b001).anti_commutator(e(0b001), metric), Just(e(0)));
+
+    let e01 = Blade::<algebra::VGA3, { BitBasis::unit(0b011) }>::new(2.0);
+    let e12 = Blade::<algebra::VGA3, { BitBasis::unit(0b110) }>::new(3.0);
+    let e02: Blade<algebra::VGA3, { BitBasis::unit(0b101) }> = e01.commutator(e12);
+    assert_eq!(e02.scale(), 6.0);
+    let zero: Blade<algebra::VGA3, { Nothing }> = e01.anti_commutator(e12);
+    assert_eq!(zero.scale(), 0.0);
+}
+
+#[test]
+fn test_inner_product_variants() {
+    let e = |index| MV::<algebra::VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let (two, e0, e1, e01) = (e(0) * 2.0, e(0b001), e(0b010), e(0b011));
+
+    // Scalars: preserved by Dorst's fat dot and the left contraction, discarded by Hestenes.
+    assert_eq!(two.inner(&e0), e0 * 2.0);
+    assert_eq!(e0.inner(&two), e0 * 2.0);
+    assert_eq!(two.inner(&two), two * 2.0);
+    assert_eq!(two.left_contraction(&e0), e0 * 2.0);
+    assert_eq!(two.right_contraction(&e0), MV::ZERO);
+    assert_eq!(e0.left_contraction(&two), MV::ZERO);
+    assert_eq!(two.hestenes_inner(&e0), MV::ZERO);
+    assert_eq!(e0.hestenes_inner(&two), MV::ZERO);
+    assert_eq!(two.hestenes_inner(&two), MV::ZERO);
+
+    // Higher grades on the left: only the right contraction and the symmetric variants remain.
+    assert_eq!(e01.inner(&e0), -e1);
+    assert_eq!(e0.inner(&e01), e1);
+    assert_eq!(e01.hestenes_inner(&e0), -e1);
+    assert_eq!(e01.right_contraction(&e0), -e1);
+    assert_eq!(e01.left_contraction(&e0), MV::ZERO);
+    assert_eq!(e0.left_contraction(&e01), e1);
+    assert_eq!(e0.right_contraction(&e01), MV::ZERO);
+
+    // The anti-contractions are the duals of the contractions.
+    for seed in 0..4 {
+        let (a, b) = (
+            sample::<algebra::PGA3>(seed),
+            sample::<algebra::PGA3>(seed + 4),
+        );
+        let dual = |x: MV<algebra::PGA3>| x.right_complement();
+        assert_eq!(
+            a.left_anti_contraction(&b),
+            (dual(a) >> dual(b)).left_complement()
+        );
+        assert_eq!(
+            a.right_anti_contraction(&b),
+            (dual(a) << dual(b)).left_complement()
+        );
+        assert_eq!(
+            a.hestenes_inner(&b),
+            (a | b) - a.grade(0) * b - a * b.grade(0) + (a.grade(0) * b.grade(0))
+        );
+    }
+
+    // In PGA3, the anti-contraction measures the weight `e3` which the metric cannot.
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let (e3, e0123) = (e(0b1000), e(0b1111));
+    assert_eq!(e3.left_contraction(&e3), MV::ZERO);
+    assert_eq!(e3.left_anti_contraction(&e3), -e0123);
+    assert_eq!(e0123.left_anti_contraction(&e3), e3);
+    assert_eq!(e3.right_anti_contraction(&e0123), e3);
+
+    let e3 = Blade::<algebra::PGA3, { BitBasis::unit(0b1000) }>::new(2.0);
+    let e0123: Blade<algebra::PGA3, { BitBasis::unit(0b1111).neg() }> =
+        e3.left_anti_contraction(e3);
+    assert_eq!(e0123.scale(), -4.0);
+    let zero: Blade<algebra::PGA3, { Nothing }> =
+        Blade::<algebra::PGA3, { BitBasis::unit(0) }>::ONE.hestenes_inner(e3);
+    assert_eq!(zero.scale(), 0.0);
+}
+
+#[test]
+fn test_anti_geometric_product() {
+    type A = algebra::PGA3;
+    let e = |index| MV::<A>::blade(1.0, NonzeroBasis::from_index(index));
+    let (e3, e0123) = (e(0b1000), e(0b1111));
+
+    for seed in 0..4 {
+        let (a, b, c) = (
+            sample::<A>(seed),
+            sample::<A>(seed + 4),
+            sample::<A>(seed + 8),
+        );
+        let dual = |x: MV<A>| x.right_complement();
+
+        // `I` is the identity of the antiproduct like `1` is the one of the geometric product.
+        assert_eq!(a.anti_geometric(&e0123), a);
+        assert_eq!(e0123.anti_geometric(&a), a);
+        assert_eq!(a.anti_geometric(&b), (dual(a) * dual(b)).left_complement());
+        assert_close(
+            a.anti_geometric(&b).anti_geometric(&c),
+            a.anti_geometric(&b.anti_geometric(&c)),
+        );
+        assert_eq!(
+            a.anti_geometric(&b).anti_reverse(),
+            b.anti_reverse().anti_geometric(&a.anti_reverse())
+        );
+        assert_eq!(a.anti_reverse().anti_reverse(), a);
+        assert_eq!(
+            a.anti_scalar_product(&b),
+            a.anti_geometric(&b).get(NonzeroBasis::from_index(0b1111))
+        );
+    }
+
+    // The weight `e3` has a unit anti-norm, even though it squares to zero.
+    assert_eq!(e3 * e3, MV::ZERO);
+    assert_eq!(e3.anti_reverse(), -e3);
+    assert_eq!(e3.anti_geometric(&e3.anti_reverse()), e0123);
+    assert_eq!(e3.anti_scalar_product(&e3.anti_reverse()), 1.0);
+
+    let e = |index| MV::<algebra::PGA2>::blade(1.0, NonzeroBasis::from_index(index));
+    let (e2, e012) = (e(0b100), e(0b111));
+    assert_eq!(e2.anti_reverse(), -e2);
+    assert_eq!(e2.anti_geometric(&e2.anti_reverse()), e012);
+    let a = sample::<algebra::PGA2>(3);
+    assert_eq!(a.anti_geometric(&e012), a);
+
+    let e3 = Blade::<A, { BitBasis::unit(0b1000) }>::new(2.0);
+    let e0123: Blade<A, { BitBasis::unit(0b1111) }> = e3.anti_geometric(e3.anti_reverse());
+    assert_eq!(e0123.scale(), 4.0);
+}
+
+#[test]
+fn test_meet_and_join() {
+    // Two planes through the origin of VGA3 meet in a line, which the regressive product finds,
+    // but two lines only span a plane, such that their regressive product vanishes.
+    let v = |x: f64, y: f64, z: f64| {
+        MV::<algebra::VGA3>::blade(x, NonzeroBasis::from_index(0b001))
+            + MV::blade(y, NonzeroBasis::from_index(0b010))
+            + MV::blade(z, NonzeroBasis::from_index(0b100))
+    };
+    let (a, b) = (v(1.0, 2.0, 0.5), v(-1.0, 0.5, 3.0));
+    assert_eq!(a & b, MV::ZERO);
+    assert_proportional(a.join(&b), a ^ b);
+    assert_proportional(a.meet(&b), MV::scalar(1.0));
+    assert_proportional(a.meet(&a.scale(3.0)), a);
+    assert_proportional(a.join(&a.scale(3.0)), a);
+
+    let (p, q) = (a ^ b, a ^ v(0.0, 1.0, 1.0));
+    assert_proportional(
+        p.join(&q),
+        v(1.0, 0.0, 0.0) ^ v(0.0, 1.0, 0.0) ^ v(0.0, 0.0, 1.0),
+    );
+    assert_proportional(p.meet(&q), a);
+    assert_proportional(p.meet(&a), a);
+    assert_proportional(p.join(&a), p);
+
+    // Small magnitudes are no zeros.
+    let (a_small, b_small) = (a.scale(1e-9), b.scale(1e-9));
+    assert_proportional(a_small.join(&b_small), (a ^ b).scale(1e-18));
+    assert_proportional(p.scale(1e-9).meet(&q.scale(1e-9)), p.meet(&q).scale(1e-18));
+
+    // In the direct representation of PGA3 with `e3` as homogeneous coordinate, two lines
+    // through a common point meet in that point, although their regressive product is zero.
+    let point = |x: f64, y: f64, z: f64| {
+        MV::<algebra::PGA3>::blade(x, NonzeroBasis::from_index(0b0001))
+            + MV::blade(y, NonzeroBasis::from_index(0b0010))
+            + MV::blade(z, NonzeroBasis::from_index(0b0100))
+            + MV::blade(1.0, NonzeroBasis::from_index(0b1000))
+    };
+    let (p, q, r) = (
+        point(1.0, 2.0, 3.0),
+        point(-1.0, 0.0, 2.0),
+        point(0.5, 4.0, -1.0),
+    );
+    let (l, m) = (p ^ q, p ^ r);
+    assert_eq!(l & m, MV::ZERO);
+    assert_proportional(l.meet(&m), p);
+    assert_proportional(l.join(&m), p ^ q ^ r);
+    assert_proportional(l.meet(&(p ^ q ^ r)), l);
+    assert_eq!(l.meet(&MV::ZERO), MV::ZERO);
+}
+
+#[test]
+fn test_factorization() {
+    fn assert_factorization<A: Signature>(blade: MV<A>, k: usize)
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        let factors = blade.factorize();
+        assert_eq!(factors.len(), k);
+        let dot = |x: &MV<A>, y: &MV<A>| x.0.iter().zip(y.0).map(|(x, y)| x * y).sum::<f64>();
+        for (i, factor) in factors.iter().enumerate() {
+            assert_eq!(factor.grades(), vec![1]);
+            if i + 1 < k {
+                assert!((dot(factor, factor) - 1.0).abs() < 1e-9);
+            }
+            for other in &factors[..i] {
+                assert!(
+                    dot(factor, other).abs() < 1e-9,
+                    "{factor} is not orthogonal to {other}"
+                );
+            }
+        }
+        let product = factors
+            .into_iter()
+            .reduce(|product, factor| product ^ factor)
+            .unwrap_or(MV::scalar(1.0));
+        assert_close(product, if k == 0 { MV::scalar(1.0) } else { blade });
+    }
+
+    fn assert_random_blades<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for k in 0..=A::DIM {
+            for seed in 0..4 {
+                let blade = (0..k)
+                    .map(|j| sample::<A>(seed + 3 * j).grade(1))
+                    .fold(MV::scalar(1.0), |blade, vector| blade ^ vector);
+                if blade.0.iter().all(|x| x.abs() < 1e-9) {
+                    continue;
+                }
+                assert!(blade.is_blade());
+                assert_factorization(blade, k);
+            }
+        }
+    }
+
+    assert_random_blades::<algebra::VGA<4>>();
+    assert_random_blades::<algebra::CGA3>();
+
+    // Null blades of the conformal model still have factors orthogonal in the coefficients.
+    let e = |index| MV::<algebra::CGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let infinity = e(0b01000) + e(0b10000);
+    assert_eq!(infinity * infinity, MV::ZERO);
+    assert_factorization(infinity, 1);
+    assert_factorization(e(0b00001) ^ infinity, 2);
+    assert_factorization(e(0b00001) ^ e(0b00010) ^ infinity, 3);
+    assert_eq!(MV::<algebra::CGA3>::ZERO.factorize(), vec![]);
+
+    // Non-finite coefficients give meaningless factors, but do not panic.
+    let mut nan = e(0b00001) ^ e(0b00010);
+    nan.0[0b00110] = f64::NAN;
+    assert_eq!(nan.factorize().len(), 2);
+}
+
+#[test]
+fn test_versor_decomposition() {
+    fn assert_reflections<A: Signature>(versor: MV<A>)
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        let reflections = versor.reflections().unwrap();
+        assert!(reflections.len() <= 2 * A::DIM);
+        for reflection in &reflections {
+            assert_eq!(reflection.grades(), vec![1]);
+            assert!((reflection.norm_squared().abs() - 1.0).abs() < 1e-9);
+        }
+        let product = reflections
+            .into_iter()
+            .fold(MV::scalar(1.0), |product, reflection| product * reflection);
+        assert_proportional(product, versor);
+    }
+
+    fn assert_random_versors<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for seed in 0..4 {
+            let mut versor = MV::scalar(1.0);
+            for j in 0..=A::DIM {
+                let vector = sample::<A>(seed + 5 * j).grade(1);
+                if vector.norm_squared().abs() < 1e-3 {
+                    continue;
+                }
+                versor = versor * vector;
+                assert_reflections(versor);
+            }
+        }
+    }
+
+    assert_random_versors::<algebra::VGA3>();
+    assert_random_versors::<algebra::Algebra<1, 3, 0>>();
+    assert_random_versors::<algebra::Algebra<2, 2, 0>>();
+    assert_random_versors::<algebra::CGA3>();
+    assert_eq!(MV::<algebra::VGA3>::scalar(2.0).reflections(), Ok(vec![]));
+
+    // A rotor by `θ` is the product of two reflections in planes at the angle `θ / 2`.
+    let e = |index| MV::<algebra::VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let rotor = e(0b011).scale(0.4).exp();
+    let reflections = rotor.reflections().unwrap();
+    assert_eq!(reflections.len(), 2);
+    assert_proportional(reflections[0] * reflections[1], rotor);
+
+    assert_eq!(
+        sample::<algebra::VGA3>(1).reflections(),
+        Err(Error::NotVersor)
+    );
+    let e = |index| MV::<algebra::PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    assert_eq!(e(0b0001).reflections(), Err(Error::DegenerateMetric));
+
+    // A screw motion splits into a rotation around and a translation along the axis `e01`.
+    let rotation = e(0b0011).scale(0.7);
+    let translation = e(0b1100).scale(1.5);
+    let motor = (rotation + translation).exp();
+    let factors = motor.simple_motors().unwrap();
+    assert_eq!(factors.len(), 2);
+    assert_close(factors[0] * factors[1], motor);
+    assert_close(factors[0] * factors[1], factors[1] * factors[0]);
+    for factor in &factors {
+        let bivector = factor.log().unwrap();
+        let square = bivector * bivector;
+        assert_close(square, square.grade(0));
+    }
+    let mut squares: Vec<f64> = factors
+        .iter()
+        .map(|factor| {
+            let bivector = factor.log().unwrap();
+            (bivector * bivector).0[0]
+        })
+        .collect();
+    squares.sort_by(f64::total_cmp);
+    assert!((squares[0] + 0.49).abs() < 1e-9 && squares[1].abs() < 1e-9);
+
+    // A small screw motion is no simple motor either.
+    let motor = (rotation + translation).scale(1e-5).exp();
+    let factors = motor.simple_motors().unwrap();
+    assert_eq!(factors.len(), 2);
+    assert_close(factors[0] * factors[1], motor);
+    let (rotor, translator) = if factors[0].0[0b0011].abs() > factors[1].0[0b0011].abs() {
+        (factors[0], factors[1])
+    } else {
+        (factors[1], factors[0])
+    };
+    assert_close(rotor.grade(2).scale(1e5), rotation);
+    assert_close(translator.grade(2).scale(1e5), translation);
+
+    let rotor = rotation.exp();
+    assert_eq!(rotor.simple_motors(), Ok(vec![rotor]));
+
+    // Double rotations of VGA<4>, including isoclinic ones with equal angles.
+    let e = |index| MV::<algebra::VGA<4>>::blade(1.0, NonzeroBasis::from_index(index));
+    for (angle1, angle2) in [(0.3, 1.1), (0.8, 0.8), (0.5, -0.5)] {
+        let rotor = (e(0b0011).scale(angle1) + e(0b1100).scale(angle2)).exp();
+        let factors = rotor.simple_motors().unwrap();
+        assert_eq!(factors.len(), 2);
+        assert_close(factors[0] * factors[1], rotor);
+        assert_close(factors[0] * factors[1], factors[1] * factors[0]);
+        for factor in &factors {
+            let bivector = factor.grade(2);
+            let square = bivector * bivector;
+            assert_close(square, square.grade(0));
+        }
+    }
+}
+
+#[test]
+fn test_sparse_multivectors() {
+    type A = algebra::CGA3;
+    for seed in 0..4 {
+        let (a, b) = (sample::<A>(seed), sample::<A>(seed + 4).grade(2));
+        let (sparse_a, sparse_b) = (SparseMV::from(a), SparseMV::from(b));
+        assert_eq!(MV::from(sparse_a.clone()), a);
+        assert_eq!(sparse_b.len(), b.0.iter().filter(|&&x| x != 0.0).count());
+        for product in PRODUCTS {
+            assert_eq!(
+                MV::from(sparse_a.product(&sparse_b, product)),
+                a.product(&b, product)
+            );
+        }
+        assert_eq!(MV::from(sparse_a.clone() * sparse_b.clone()), a * b);
+        assert_eq!(MV::from(sparse_a.clone() + sparse_b.clone()), a + b);
+        assert_eq!(MV::from(sparse_a.clone() - sparse_b.clone()), a - b);
+        assert_eq!(MV::from(sparse_a.reverse()), a.reverse());
+        assert_eq!(MV::from(sparse_a.involute()), a.involute());
+        assert_eq!(MV::from(sparse_a.conjugate()), a.conjugate());
+        assert_eq!(MV::from(!sparse_a.clone()), !a);
+        assert_eq!(MV::from(sparse_a.grade(3)), a.grade(3));
+        assert_eq!(sparse_a.scalar_product(&sparse_b), a.scalar_product(&b));
+        assert_eq!(sparse_a.to_string(), a.to_string());
+    }
+
+    // Zero coefficients are pruned, such that products of blades stay small.
+    let e = |unit| {
+        SparseMV::<A>::blade(
+            1.0,
+            NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit,
+            },
+        )
+    };
+    let (e0, e1) = (e(0b001), e(0b010));
+    assert!((e0.clone() ^ e0.clone()).is_empty());
+    assert_eq!((e0.clone() + e1.clone() - e1.clone()), e0);
+    assert_eq!((e0.clone() * e1.clone()).len(), 1);
+    assert_eq!(SparseMV::<A>::ZERO.to_string(), "0");
+
+    // Sparse multivectors need no dense storage or Cayley tables, even for many dimensions.
+    type Large = algebra::Algebra<24, 8, 0>;
+    let e = |i: u64| {
+        SparseMV::<Large>::blade(
+            1.0,
+            NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit: 1 << i,
+            },
+        )
+    };
+    let rotor = SparseMV::scalar(0.6) + (e(0) * e(20)) * 0.8;
+    let rotated = rotor.clone() * e(0) * rotor.reverse();
+    assert_eq!(rotated.len(), 2);
+    assert!(
+        (rotated.get(NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit: 1
+        }) + 0.28)
+            .abs()
+            < 1e-12
+    );
+    assert_eq!((e(31) * e(31)), SparseMV::scalar(-1.0));
+}
+
+#[test]
+fn test_graded_multivectors() {
+    // The grade rules contain all grades of the products of blades in any metric.
+    fn check_grade_rules<A: Signature>()
+    where
+        [(); A::BASIS_BLADE_COUNT]:,
+        [(); A::DIM]:,
+    {
+        for (i, j) in (0..=A::DIM).flat_map(|i| (0..=A::DIM).map(move |j| (i, j))) {
+            let (a, b) = (sample::<A>(i).grade(i), sample::<A>(j + 3).grade(j));
+            for product in PRODUCTS {
+                let grades = Grades::single(i).product(Grades::single(j), product, A::DIM);
+                for k in a.product(&b, product).grades() {
+                    assert!(grades.contains(k), "{product:?} of grades {i} and {j}");
+                }
+            }
+        }
+    }
+    check_grade_rules::<algebra::VGA3>();
+    check_grade_rules::<algebra::PGA3>();
+    check_grade_rules::<algebra::CGA3>();
+
+    type A = algebra::VGA3;
+    let a: Vector<A> = Graded([1.0, 2.0, 3.0]);
+    let b: Vector<A> = Graded::from_mv(&sample::<A>(2));
+    let bivector: Bivector<A> = a ^ b;
+    assert_eq!(bivector.0.len(), 3);
+    assert_eq!(bivector.to_mv(), a.to_mv() ^ b.to_mv());
+    let rotor: Even<A> = a * b;
+    assert_eq!(rotor.0.len(), 4);
+    assert_eq!(MV::from(rotor), a.to_mv() * b.to_mv());
+    let odd: Odd<A> = rotor * a;
+    assert_eq!(odd.to_mv(), a.to_mv() * b.to_mv() * a.to_mv());
+    let volume: Trivector<A> = bivector ^ a;
+    assert_eq!(volume, Graded::ZERO);
+    let volume: Pseudoscalar<A> = bivector ^ Vector::<A>::from_mv(&sample::<A>(5));
+    assert_eq!(volume.0.len(), 1);
+    let normal: Vector<A> = !bivector;
+    assert_eq!(normal.to_mv(), !bivector.to_mv());
+    let scalar: Graded<A, { Grades::single(0) }> = a | b;
+    assert_eq!(scalar.0[0], a.scalar_product(&b));
+    assert_eq!(bivector.reverse(), -bivector);
+
+    // The motors of PGA3 stay in the even subalgebra of 8 coefficients.
+    type P = algebra::PGA3;
+    let (m1, m2): (Even<P>, Even<P>) = (
+        Graded::from_mv(&sample::<P>(1)),
+        Graded::from_mv(&sample::<P>(2)),
+    );
+    let motor: Even<P> = m1 * m2;
+    assert_eq!(motor.0.len(), 8);
+    assert_eq!(motor.to_mv(), m1.to_mv() * m2.to_mv());
+    assert_eq!(Grades::even(3), Grades(0b0101));
+    assert_eq!(Grades::all(3).count(3), 8);
+    assert_eq!(Grades::all(0), Grades(0b1));
+    assert_eq!(Grades::all(63), Grades(u64::MAX));
+    assert_eq!(Grades::even(63), Grades(0x5555_5555_5555_5555));
+    assert_eq!(Grades::single(31).count(63), 916_312_070_471_295_267);
+    assert_eq!(Grades::even(63).count(63), 1 << 62);
+    assert_eq!(Grades::all(62).count(62), 1 << 62);
+
+    // The typed products agree with the dense ones for all combinations of stored grades.
+    type C = algebra::CGA3;
+    for seed in 0..4 {
+        let (a, b): (Even<C>, Odd<C>) = (
+            Graded::from_mv(&sample::<C>(seed)),
+            Graded::from_mv(&sample::<C>(seed + 4)),
+        );
+        let (a_mv, b_mv) = (a.to_mv(), b.to_mv());
+        assert_eq!(a.geometric(&b).to_mv(), a_mv * b_mv);
+        assert_eq!(a.exterior(&b).to_mv(), a_mv ^ b_mv);
+        assert_eq!(a.regressive(&b).to_mv(), a_mv & b_mv);
+        assert_eq!(a.left_contraction(&b).to_mv(), a_mv >> b_mv);
+        assert_eq!(a.right_contraction(&b).to_mv(), a_mv << b_mv);
+        assert_eq!(a.inner(&b).to_mv(), a_mv | b_mv);
+        assert_eq!(a.scalar_product(&a), a_mv.scalar_product(&a_mv));
+        assert_eq!(a.scalar_product(&b), 0.0);
+        let bivector: Bivector<C> = Graded::from_mv(&sample::<C>(seed));
+        assert_eq!((bivector * b).to_mv(), bivector.to_mv() * b_mv);
+    }
+}
+
+#[test]
+fn test_bilinear_forms() {
+    type A = algebra::CGA3;
+    let assert_close = |lhs: &SparseMV<A>, rhs: &SparseMV<A>| {
+        let difference = lhs.clone() - rhs.clone();
+        assert!(
+            difference.iter().all(|(_, value)| value.abs() < 1e-9),
+            "{lhs} != {rhs}"
+        );
+    };
+    let vector = |coefficients: [f64; 5]| {
+        (0..5).fold(SparseMV::<A>::ZERO, |vector, i| {
+            let basis = NonzeroBitBasis {
+                sign: Sign::Pos,
+                unit: 1 << i,
+            };
+            vector + SparseMV::blade(coefficients[i], basis)
+        })
+    };
+
+    // Diagonal forms agree with the bitmask products.
+    let diagonal = BilinearForm::<5>::from_metric(Metric::signature(4, 1));
+    for seed in 0..4 {
+        let a = SparseMV::from(sample::<A>(seed));
+        let b = SparseMV::from(sample::<A>(seed + 4));
+        for product in PRODUCTS {
+            assert_eq!(diagonal.product(&a, &b, product), a.product(&b, product));
+        }
+    }
+
+    // The null basis of the conformal metric.
+    let conformal = BilinearForm::<5>::conformal();
+    let origin = SparseMV::<A>::blade(1.0, BilinearForm::<5>::origin());
+    let infinity = SparseMV::<A>::blade(1.0, BilinearForm::<5>::infinity());
+    assert!(conformal.geometric(&origin, &origin).is_empty());
+    assert!(conformal.geometric(&infinity, &infinity).is_empty());
+    assert_close(
+        &conformal.geometric(&origin, &infinity),
+        &(SparseMV::scalar(-1.0) + (origin.clone() ^ infinity.clone())),
+    );
+    let up = |[x, y, z]: [f64; 3]| vector([x, y, z, 1.0, (x * x + y * y + z * z) / 2.0]);
+    let (p, q) = (up([1.0, 2.0, 3.0]), up([0.0, -1.0, 2.0]));
+    assert!(conformal.geometric(&p, &p).is_empty());
+    assert!((conformal.scalar_product(&p, &q) + 5.5).abs() < 1e-9);
+
+    // `e₀ = (e₃ + e₄) / 2` and `e∞ = e₄ - e₃` in terms of the diagonal metric, such that
+    // mapping the blades to the diagonal metric commutes with all products.
+    let map = |mv: &SparseMV<A>| {
+        mv.iter().fold(SparseMV::ZERO, |result, (basis, value)| {
+            let blade = (0..5).filter(|i| basis.unit & (1 << i) != 0).fold(
+                SparseMV::scalar(value),
+                |blade, i| {
+                    blade
+                        ^ match i {
+                            3 => vector([0.0, 0.0, 0.0, 0.5, 0.5]),
+                            4 => vector([0.0, 0.0, 0.0, -1.0, 1.0]),
+                            _ => vector(std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 })),
+                        }
+                },
+            );
+            result + blade
+        })
+    };
+    for seed in 0..4 {
+        let a = SparseMV::from(sample::<A>(seed));
+        let b = SparseMV::from(sample::<A>(seed + 4));
+        for product in PRODUCTS {
+            assert_close(
+                &map(&conformal.product(&a, &b, product)),
+                &map(&a).product(&map(&b), product),
+            );
+        }
+    }
+
+    // Arbitrary and degenerate forms.
+    let form = BilinearForm::new([
+        [2.0, 1.0, 0.0, 0.5, 0.0],
+        [1.0, -1.0, 0.3, 0.0, 0.0],
+        [0.0, 0.3, 0.0, 1.0, 0.0],
+        [0.5, 0.0, 1.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0],
+    ])
+    .unwrap();
+    let (u, v) = ([1.0, -2.0, 0.5, 3.0, 1.0], [0.0, 1.0, 2.0, -1.0, 4.0]);
+    assert_close(
+        &form.geometric(&vector(u), &vector(u)),
+        &SparseMV::scalar(form.apply(u, u)),
+    );
+    assert!((form.scalar_product(&vector(u), &vector(v)) - form.apply(u, v)).abs() < 1e-9);
+    for seed in 0..4 {
+        let [a, b, c] =
+            [seed, seed + 4, seed + 8].map(|seed| SparseMV::from(sample::<A>(seed) * 0.25));
+        assert_close(
+            &form.geometric(&form.geometric(&a, &b), &c),
+            &form.geometric(&a, &form.geometric(&b, &c)),
+        );
+    }
+    assert_eq!(
+        BilinearForm::new([[1.0, 2.0], [0.0, 1.0]]),
+        Err(Error::NotSymmetric)
+    );
+}
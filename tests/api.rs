@@ -0,0 +1,163 @@
+//! Exercises the public API purely from outside of the crate.
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use generic_ga::prelude::*;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[test]
+fn algebra_properties() {
+    assert_eq!(VGA3::DIM, 3);
+    assert_eq!(VGA3::BASIS_BLADE_COUNT, 8);
+    assert_eq!(PGA3::DIM, 4);
+    assert_eq!(CGA3::DIM, 5);
+    assert_eq!(CGA3::BASIS_BLADE_COUNT, 32);
+    assert_eq!(<Complex as Signature>::Q, 1);
+    assert_eq!(
+        PGA2::metric(),
+        Metric([Square::Pos, Square::Pos, Square::Zero])
+    );
+    assert_eq!(CGA2::metric(), Metric::signature(3, 1));
+}
+
+#[test]
+fn basis_products() {
+    let metric = PGA3::metric();
+    let e0 = NonzeroBasis::<4>::from_index(0b0001);
+    let e1 = NonzeroBasis::<4>::from_index(0b0010);
+    let e3 = NonzeroBasis::<4>::from_index(0b1000);
+
+    assert_eq!(
+        e1.geometric(e0, metric),
+        Just(NonzeroBasis {
+            sign: Sign::Neg,
+            unit: [true, true, false, false]
+        })
+    );
+    assert_eq!(
+        e3.geometric(e3, metric),
+        Nothing,
+        "The degenerate basis vector squares to zero"
+    );
+    assert_eq!(e0.exterior(e0, metric), Nothing);
+    assert_eq!(format!("{}", e1.geometric(e0, metric)), "-e01");
+}
+
+#[test]
+fn multivector_coefficients() {
+    let e12 = NonzeroBasis::<3>::from_index(0b110);
+    let e21 = e12.neg();
+
+    let mv = MV::<VGA3>::blade(2.0, e21);
+    assert_eq!(mv.get(e12), -2.0);
+    assert_eq!(mv.get(e21), 2.0);
+    assert_eq!(mv, MV([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -2.0, 0.0]));
+    assert_eq!(MV::<CGA3>::scalar(1.0).0[0], 1.0);
+    assert_eq!(MV::<PGA3>::ZERO, MV([0.0; 16]));
+}
+
+#[test]
+fn multivector_products() {
+    let e1 = MV::<VGA2>::blade(1.0, NonzeroBasis::from_index(0b01));
+    let e2 = MV::<VGA2>::blade(1.0, NonzeroBasis::from_index(0b10));
+    let e12 = e1.geometric(&e2);
+
+    assert_eq!(e12, e1.exterior(&e2));
+    assert_eq!(e12.geometric(&e12), -MV::scalar(1.0));
+    assert_eq!(e1.left_contraction(&e12), e2);
+    assert_eq!((e1 + e2) * 2.0 - e2, e1 * 2.0 + e2);
+    assert_eq!(format!("{}", e1 * 3.0 + e12), "3e0 + 1e01");
+}
+
+#[test]
+fn typed_blades() {
+    let e0 = Blade::<PGA2, { BitBasis::unit(0b001) }>::new(2.0);
+    let e2 = Blade::<PGA2, { BitBasis::unit(0b100) }>::ONE;
+
+    let e02: Blade<PGA2, { BitBasis::unit(0b101) }> = e0.geometric(e2);
+    assert_eq!(e02.scale(), 2.0);
+    let vanishing: Blade<PGA2, { Nothing }> = e2.geometric(e2);
+    assert_eq!(vanishing.scale(), 0.0);
+}
+
+#[test]
+fn inverses() {
+    let e = |index| MV::<PGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let translator = e(0) + e(0b1001) * 0.5;
+    assert_eq!(translator * translator.inverse().unwrap(), e(0));
+    assert_eq!(translator.versor_inverse(), Ok(e(0) - e(0b1001) * 0.5));
+    assert_eq!(e(0b1000).inverse(), Err(generic_ga::Error::NotInvertible));
+}
+
+#[test]
+fn exponentials() {
+    let e = |index| MV::<VGA3>::blade(1.0, NonzeroBasis::from_index(index));
+    let quarter_turn = (e(0b011) * -std::f64::consts::FRAC_PI_4).exp();
+    let rotated = quarter_turn * e(0b001) * quarter_turn.reverse();
+    assert!(
+        (rotated - e(0b010))
+            .0
+            .iter()
+            .all(|value| value.abs() < 1e-12)
+    );
+
+    let log = quarter_turn.log().unwrap();
+    assert!((log.get(NonzeroBasis::from_index(0b011)) + std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+}
+
+/// Integers modulo 7, standing in for an exact user-defined number type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mod7(u8);
+
+impl Add for Mod7 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+}
+
+impl Sub for Mod7 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Mod7 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Mod7((self.0 * rhs.0) % 7)
+    }
+}
+
+impl Neg for Mod7 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Mod7((7 - self.0) % 7)
+    }
+}
+
+impl Scalar for Mod7 {
+    const ZERO: Self = Mod7(0);
+    const ONE: Self = Mod7(1);
+}
+
+#[test]
+fn custom_scalars() {
+    let e = |index| MV::<VGA2, Mod7>::blade(Mod7(1), NonzeroBasis::from_index(index));
+    let (e0, e1) = (e(0b01), e(0b10));
+
+    let rotor = MV::scalar(Mod7(3)) + e0 * e1 * Mod7(2);
+    assert_eq!(
+        (rotor * rotor.reverse()).0,
+        [Mod7(6), Mod7(0), Mod7(0), Mod7(0)]
+    );
+    assert_eq!(Sign::Neg * Mod7(2), Mod7(5));
+
+    let e0 = Blade::<VGA2, { BitBasis::unit(0b01) }, f32>::new(0.5);
+    let e1 = Blade::<VGA2, { BitBasis::unit(0b10) }, f32>::new(4.0);
+    let e01: Blade<VGA2, { BitBasis::unit(0b11) }, f32> = e0 * e1;
+    assert_eq!(e01.scale(), 2.0);
+}
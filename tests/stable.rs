@@ -0,0 +1,79 @@
+//! Exercises the runtime layer, which builds without the `nightly` feature.
+
+use generic_ga::{BitMetric, NonzeroBitBasis, prelude::*};
+
+fn e<A: Signature>(unit: u64) -> SparseMV<A> {
+    SparseMV::blade(
+        1.0,
+        NonzeroBitBasis {
+            sign: Sign::Pos,
+            unit,
+        },
+    )
+}
+
+#[test]
+fn bit_metrics() {
+    assert_eq!(
+        PGA3::BIT_METRIC,
+        BitMetric::new(Metric([
+            Square::Pos,
+            Square::Pos,
+            Square::Pos,
+            Square::Zero
+        ]))
+    );
+    assert_eq!(CGA3::BIT_METRIC, BitMetric::signature(4, 1, 0));
+    assert_eq!(VGA3::BIT_METRIC.pseudoscalar(), 0b111);
+}
+
+#[test]
+fn bit_basis_products() {
+    let metric = PGA3::BIT_METRIC;
+    let e0 = NonzeroBitBasis {
+        sign: Sign::Pos,
+        unit: 0b0001,
+    };
+    let e1 = NonzeroBitBasis {
+        sign: Sign::Pos,
+        unit: 0b0010,
+    };
+
+    assert_eq!(
+        e1.geometric(e0, metric),
+        Just(NonzeroBitBasis {
+            sign: Sign::Neg,
+            unit: 0b0011,
+        })
+    );
+    let e3 = NonzeroBitBasis {
+        sign: Sign::Pos,
+        unit: 0b1000,
+    };
+    assert_eq!(e3.geometric(e3, metric), Nothing);
+    assert_eq!(e1.exterior(e1, metric), Nothing);
+}
+
+#[test]
+fn sparse_multivectors() {
+    let (e1, e2, e3) = (e::<VGA3>(0b001), e::<VGA3>(0b010), e::<VGA3>(0b100));
+    let rotor = SparseMV::scalar(1.0) + e1.clone() * e2.clone();
+
+    assert_eq!(rotor.to_string(), "1 + 1e01");
+    assert_eq!((e1.clone() ^ e2.clone() ^ e3.clone()).to_string(), "1e012");
+    assert_eq!(e2.clone() * e1.clone(), -(e1.clone() * e2.clone()));
+    assert_eq!((e1.clone() * e1.clone()).to_string(), "1");
+    assert_eq!(rotor.reverse() * rotor.clone(), SparseMV::scalar(2.0));
+    assert_eq!(!e3, e1.clone() ^ e2.clone());
+    assert!((e1.clone() ^ e1).is_empty());
+}
+
+#[test]
+fn conformal_null_basis() {
+    let form = BilinearForm::<5>::conformal();
+    let origin = SparseMV::<CGA3>::blade(1.0, BilinearForm::<5>::origin());
+    let infinity = SparseMV::<CGA3>::blade(1.0, BilinearForm::<5>::infinity());
+
+    assert!(form.geometric(&origin, &origin).is_empty());
+    assert!((form.scalar_product(&origin, &infinity) + 1.0).abs() < 1e-12);
+}